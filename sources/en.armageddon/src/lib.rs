@@ -14,6 +14,7 @@ impl Impl for Armageddon {
 	fn params(&self) -> Params {
 		Params {
 			base_url: BASE_URL.into(),
+			fallback_filters: include_str!("../res/fallback_filters.json").into(),
 			series_title_selector: "h1.kdt8-left-title".into(),
 			series_cover_selector: ".kdt8-cover img".into(),
 			series_description_selector: ".kdt8-synopsis".into(),
@@ -38,5 +39,7 @@ register_source!(
 	MangaThemesia<Armageddon>,
 	Home,
 	ImageRequestProvider,
-	DeepLinkHandler
+	PageImageProcessor,
+	DeepLinkHandler,
+	DynamicFilters
 );
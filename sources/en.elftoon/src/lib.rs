@@ -14,6 +14,7 @@ impl Impl for ElfToon {
 	fn params(&self) -> Params {
 		Params {
 			base_url: BASE_URL.into(),
+			fallback_filters: include_str!("../res/fallback_filters.json").into(),
 			chapter_list_selector: "#chapterlist li:not(:has(.gem-price-icon))".into(),
 			..Default::default()
 		}
@@ -24,5 +25,7 @@ register_source!(
 	MangaThemesia<ElfToon>,
 	Home,
 	ImageRequestProvider,
-	DeepLinkHandler
+	PageImageProcessor,
+	DeepLinkHandler,
+	DynamicFilters
 );
@@ -1,5 +1,5 @@
 #![no_std]
-use aidoku::{prelude::*, Source};
+use aidoku::{Source, prelude::*};
 use iken::{Iken, Impl, Params};
 
 const BASE_URL: &str = "https://hivetoons.org";
@@ -21,4 +21,10 @@ impl Impl for HiveScans {
 	}
 }
 
-register_source!(Iken<HiveScans>, Home, DeepLinkHandler);
+register_source!(
+	Iken<HiveScans>,
+	ListingProvider,
+	Home,
+	DynamicFilters,
+	DeepLinkHandler
+);
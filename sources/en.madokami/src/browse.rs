@@ -0,0 +1,121 @@
+use crate::{BASE_URL, auth::AuthedRequest, helpers};
+use aidoku::{
+	Chapter, Manga, MangaWithChapter, Result,
+	alloc::{string::String, vec::Vec},
+	helpers::uri::decode_uri,
+	imports::{
+		html::Document,
+		net::Request,
+		std::{current_date, parse_date_with_options},
+	},
+	prelude::*,
+};
+
+// the title of a directory is its last path component that doesn't start with !
+fn directory_title(path: &str) -> Option<String> {
+	path.split('/')
+		.rev()
+		.map(decode_uri)
+		.find(|segment| !segment.is_empty() && !segment.starts_with('!'))
+}
+
+fn parse_upload_date(date: String) -> Option<i64> {
+	if date.ends_with("ago") {
+		Some(helpers::parse_relative_date(&date, current_date()))
+	} else {
+		parse_date_with_options(date, "yyyy-MM-dd HH:mm", "en_US", "current")
+	}
+}
+
+pub fn get_html(path: &str) -> Result<Document> {
+	Ok(Request::get(format!("{BASE_URL}{path}"))?
+		.authed()?
+		.html()?)
+}
+
+/// Parses the recent uploads table, keeping only the newest file of each series.
+pub fn parse_recent(html: &Document) -> Vec<MangaWithChapter> {
+	let mut entries: Vec<MangaWithChapter> = Vec::new();
+	let Some(rows) = html.select("div.container table tbody tr") else {
+		return entries;
+	};
+	for row in rows {
+		let Some(link) = row.select_first("td:nth-child(1) a") else {
+			continue;
+		};
+		let Some(path) = link
+			.attr("abs:href")
+			.and_then(|url| url.strip_prefix(BASE_URL).map(String::from))
+		else {
+			continue;
+		};
+		// the series is the directory containing the file
+		let Some((series_key, _)) = path.rsplit_once('/') else {
+			continue;
+		};
+		if entries.iter().any(|entry| entry.manga.key == series_key) {
+			continue;
+		}
+		let Some(title) = directory_title(series_key) else {
+			continue;
+		};
+
+		let filename = link.text();
+		let info = filename
+			.as_deref()
+			.map(helpers::parse_archive_filename)
+			.unwrap_or_default();
+		let chapter_key = row
+			.select_first("a[href*=\"/reader\"]")
+			.and_then(|el| el.attr("href"))
+			.and_then(|href| href.find("/reader").map(|idx| String::from(&href[idx..])))
+			.unwrap_or_else(|| format!("/reader{path}"));
+
+		entries.push(MangaWithChapter {
+			manga: Manga {
+				key: series_key.into(),
+				title,
+				url: Some(format!("{BASE_URL}{series_key}")),
+				..Default::default()
+			},
+			chapter: Chapter {
+				url: Some(format!("{BASE_URL}{chapter_key}")),
+				key: chapter_key,
+				title: filename,
+				volume_number: info.volume_number,
+				chapter_number: info.chapter_number,
+				scanlators: (!info.scanlators.is_empty()).then_some(info.scanlators),
+				date_uploaded: row
+					.select_first("td:nth-child(3)")
+					.and_then(|el| el.text())
+					.and_then(parse_upload_date),
+				..Default::default()
+			},
+		});
+	}
+	entries
+}
+
+/// Parses the subdirectories listed on a directory index page.
+pub fn parse_directory(html: &Document) -> Vec<Manga> {
+	html.select("table#index-table > tbody > tr > td:nth-child(1) a")
+		.map(|els| {
+			els.filter_map(|el| {
+				let url = el.attr("abs:href")?;
+				let key: String = url.strip_prefix(BASE_URL)?.trim_end_matches('/').into();
+				let title = el
+					.text()
+					.map(|s| s.trim().trim_end_matches('/').into())
+					.filter(|s: &String| !s.is_empty())
+					.or_else(|| directory_title(&key))?;
+				Some(Manga {
+					url: Some(url),
+					key,
+					title,
+					..Default::default()
+				})
+			})
+			.collect()
+		})
+		.unwrap_or_default()
+}
@@ -1,3 +1,5 @@
+use aidoku::alloc::{string::String, vec::Vec};
+
 // parses a relative date string (e.g. "21 hours ago")
 pub fn parse_relative_date(date: &str, current_date: i64) -> i64 {
 	// extract the first number found in the string
@@ -36,3 +38,307 @@ pub fn parse_relative_date(date: &str, current_date: i64) -> i64 {
 
 	current_date - offset
 }
+
+// archive extensions stripped before parsing
+const EXTENSIONS: &[&str] = &[".zip", ".cbz", ".rar", ".cbr", ".7z", ".cb7", ".pdf"];
+
+// bracketed tags that aren't scanlation groups
+const NON_GROUP_TAGS: &[&str] = &[
+	"digital",
+	"digital-hd",
+	"digital hd",
+	"c2c",
+	"f",
+	"f2",
+	"hq",
+	"lq",
+	"color",
+	"colored",
+	"colour",
+	"official",
+	"webtoon",
+	"raw",
+	"complete",
+	"completed",
+	"incomplete",
+];
+
+// bracketed tags marking bonus content rather than a regular chapter
+const EXTRA_TAGS: &[&str] = &["omake", "extra", "extras", "bonus", "special", "side story"];
+
+#[derive(Debug, Default, PartialEq)]
+pub struct ArchiveInfo {
+	pub volume_number: Option<f32>,
+	pub chapter_number: Option<f32>,
+	pub scanlators: Vec<String>,
+}
+
+// parses a volume or chapter number, using the end of a range (e.g. "001-005")
+// so that tracking covers every chapter in the archive
+fn parse_number(s: &str) -> Option<f32> {
+	let s = s.trim_start_matches('.');
+	let end = s
+		.rfind('-')
+		.map(|idx| &s[idx + 1..])
+		.filter(|end| !end.is_empty())
+		.unwrap_or(s);
+	let digits = end
+		.find(|c: char| !c.is_ascii_digit() && c != '.')
+		.map(|idx| &end[..idx])
+		.unwrap_or(end)
+		.trim_end_matches('.');
+	if digits.is_empty() {
+		return None;
+	}
+	digits.parse::<f32>().ok()
+}
+
+// parses a `v01`, `vol.01`, `c012.5` or `ch.012` style token
+fn parse_token(token: &str) -> Option<(char, &str)> {
+	let lower = token.to_ascii_lowercase();
+	for (prefix, kind) in [
+		("vol.", 'v'),
+		("vol", 'v'),
+		("ch.", 'c'),
+		("ch", 'c'),
+		("v", 'v'),
+		("c", 'c'),
+	] {
+		if lower.starts_with(prefix)
+			&& token[prefix.len()..]
+				.chars()
+				.next()
+				.is_some_and(|c| c.is_ascii_digit())
+		{
+			return Some((kind, &token[prefix.len()..]));
+		}
+	}
+	None
+}
+
+fn is_year(s: &str) -> bool {
+	// e.g. "2020" or "2015-2016"
+	s.split('-')
+		.all(|part| part.len() == 4 && part.chars().all(|c| c.is_ascii_digit()))
+}
+
+/// Parses the volume, chapter and scanlation groups from a Madokami archive filename.
+///
+/// Handles the common naming conventions, e.g. `Title v01 c001-005 (2020) (Digital) (Group).cbz`,
+/// `Title - c012.5 (v02) [Group].zip` and whole-volume archives like `Title v03.zip`.
+pub fn parse_archive_filename(filename: &str) -> ArchiveInfo {
+	let mut name = filename.trim();
+	for ext in EXTENSIONS {
+		if name.len() > ext.len() && name[name.len() - ext.len()..].eq_ignore_ascii_case(ext) {
+			name = &name[..name.len() - ext.len()];
+			break;
+		}
+	}
+
+	let mut info = ArchiveInfo::default();
+	let mut plain = String::new();
+	let mut is_extra = false;
+
+	// split out the bracketed and parenthesized tags
+	let mut rest = name;
+	while let Some(open_idx) = rest.find(['(', '[', '{']) {
+		plain.push_str(&rest[..open_idx]);
+		plain.push(' ');
+		let close = match rest.as_bytes()[open_idx] {
+			b'(' => ')',
+			b'[' => ']',
+			_ => '}',
+		};
+		let Some(close_idx) = rest[open_idx..].find(close).map(|idx| idx + open_idx) else {
+			rest = &rest[open_idx + 1..];
+			continue;
+		};
+		let tag = rest[open_idx + 1..close_idx].trim();
+		rest = &rest[close_idx + 1..];
+
+		if tag.is_empty() || is_year(tag) {
+			continue;
+		}
+		let lower = tag.to_ascii_lowercase();
+		if NON_GROUP_TAGS.contains(&lower.as_str()) {
+			continue;
+		}
+		if EXTRA_TAGS.contains(&lower.as_str()) {
+			is_extra = true;
+			continue;
+		}
+		// a volume reference, e.g. "(v42)"
+		if let Some(('v', number)) = parse_token(tag)
+			&& !tag.contains(' ')
+		{
+			if info.volume_number.is_none() {
+				info.volume_number = parse_number(number);
+			}
+			continue;
+		}
+		// a page count or similar, e.g. "(of 12)" or "(200 pages)"
+		if lower.starts_with("of ") || lower.ends_with("pages") {
+			continue;
+		}
+		info.scanlators.push(tag.into());
+	}
+	plain.push_str(rest);
+
+	let tokens: Vec<&str> = plain.split_whitespace().collect();
+	let mut previous_was_marker = false;
+	for (idx, token) in tokens.iter().enumerate() {
+		let Some((kind, number)) = parse_token(token) else {
+			previous_was_marker = *token == "-";
+			continue;
+		};
+		// avoid matching titles like "C3", chapters are zero padded or follow a marker
+		let padded = number.split(['-', '.']).next().is_some_and(|n| n.len() > 1);
+		let is_number = padded || previous_was_marker || idx + 1 == tokens.len();
+		previous_was_marker = kind == 'v';
+		if !is_number {
+			continue;
+		}
+		match kind {
+			'v' => info.volume_number = parse_number(number).or(info.volume_number),
+			_ => info.chapter_number = parse_number(number).or(info.chapter_number),
+		}
+	}
+
+	// bonus content shares the number of the chapter it follows, so it isn't given one to
+	// keep it from being tracked as that chapter (the marker stays in the filename title)
+	if is_extra {
+		info.chapter_number = None;
+	}
+
+	info
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{ArchiveInfo, parse_archive_filename};
+	use aidoku::alloc::{string::String, vec::Vec};
+	use aidoku_test::aidoku_test;
+
+	fn info(volume: Option<f32>, chapter: Option<f32>, scanlators: &[&str]) -> ArchiveInfo {
+		ArchiveInfo {
+			volume_number: volume,
+			chapter_number: chapter,
+			scanlators: scanlators
+				.iter()
+				.map(|s| String::from(*s))
+				.collect::<Vec<_>>(),
+		}
+	}
+
+	#[aidoku_test]
+	fn test_volume_and_chapter_range() {
+		assert_eq!(
+			parse_archive_filename("One Piece v100 c1005-1015 (2021) (Digital) (1r0n).cbz"),
+			info(Some(100.0), Some(1015.0), &["1r0n"])
+		);
+	}
+
+	#[aidoku_test]
+	fn test_whole_volume() {
+		assert_eq!(
+			parse_archive_filename("Vagabond v37 (2015) (Digital) (LuCaZ).cbz"),
+			info(Some(37.0), None, &["LuCaZ"])
+		);
+		assert_eq!(
+			parse_archive_filename("Berserk v01 (2003) (Digital) (danke-Empire).cbz"),
+			info(Some(1.0), None, &["danke-Empire"])
+		);
+	}
+
+	#[aidoku_test]
+	fn test_chapter_with_volume_reference() {
+		assert_eq!(
+			parse_archive_filename("Berserk - c364 (v42) [Band of the Hawk].zip"),
+			info(Some(42.0), Some(364.0), &["Band of the Hawk"])
+		);
+	}
+
+	#[aidoku_test]
+	fn test_decimal_chapter() {
+		assert_eq!(
+			parse_archive_filename("Kaguya-sama wa Kokurasetai - c012.5 [LHTranslation].zip"),
+			info(None, Some(12.5), &["LHTranslation"])
+		);
+	}
+
+	#[aidoku_test]
+	fn test_volume_range() {
+		assert_eq!(
+			parse_archive_filename("Yotsuba to! v01-03 (2016) (Digital) (Lovag).zip"),
+			info(Some(3.0), None, &["Lovag"])
+		);
+	}
+
+	#[aidoku_test]
+	fn test_omake_marker() {
+		assert_eq!(
+			parse_archive_filename("Oyasumi Punpun - c147 (Omake) [Hox].zip"),
+			info(None, None, &["Hox"])
+		);
+		assert_eq!(
+			parse_archive_filename("Dungeon Meshi v14 (Extra) (2024) (Digital) (1r0n).cbz"),
+			info(Some(14.0), None, &["1r0n"])
+		);
+	}
+
+	#[aidoku_test]
+	fn test_multiple_groups() {
+		assert_eq!(
+			parse_archive_filename("Chainsaw Man - c097 (2022) [Cyan] [Mangaplus].zip"),
+			info(None, Some(97.0), &["Cyan", "Mangaplus"])
+		);
+	}
+
+	#[aidoku_test]
+	fn test_title_with_number_like_prefix() {
+		assert_eq!(
+			parse_archive_filename("C3 - Cube x Cursed x Curious v01 (2011) [Kuro].zip"),
+			info(Some(1.0), None, &["Kuro"])
+		);
+	}
+
+	#[aidoku_test]
+	fn test_vol_ch_prefixes() {
+		assert_eq!(
+			parse_archive_filename("Mushishi Vol.05 Ch.021 [Del Rey].rar"),
+			info(Some(5.0), Some(21.0), &["Del Rey"])
+		);
+	}
+
+	#[aidoku_test]
+	fn test_unpadded_chapter_at_end() {
+		assert_eq!(
+			parse_archive_filename("Blame! c7.cbz"),
+			info(None, Some(7.0), &[])
+		);
+	}
+
+	#[aidoku_test]
+	fn test_no_numbers() {
+		assert_eq!(
+			parse_archive_filename("Fight!! Ippo - Artbook (Digital).zip"),
+			info(None, None, &[])
+		);
+	}
+
+	#[aidoku_test]
+	fn test_year_range_and_digital_hd() {
+		assert_eq!(
+			parse_archive_filename("Akira v02 (2001-2002) (Digital-HD) (Empire).cbz"),
+			info(Some(2.0), None, &["Empire"])
+		);
+	}
+
+	#[aidoku_test]
+	fn test_chapter_without_group() {
+		assert_eq!(
+			parse_archive_filename("Tsugumomo c001 (2014).zip"),
+			info(None, Some(1.0), &[])
+		);
+	}
+}
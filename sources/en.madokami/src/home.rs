@@ -0,0 +1,30 @@
+use crate::{Madokami, browse};
+use aidoku::{
+	Home, HomeComponent, HomeComponentValue, HomeLayout, Listing, Result, alloc::vec, prelude::*,
+};
+
+impl Home for Madokami {
+	fn get_home(&self) -> Result<HomeLayout> {
+		let html = browse::get_html("/recent")?;
+		let entries = browse::parse_recent(&html);
+		if entries.is_empty() {
+			bail!("No recent uploads found. Make sure you are logged in.");
+		}
+
+		Ok(HomeLayout {
+			components: vec![HomeComponent {
+				title: Some("Recent Uploads".into()),
+				subtitle: None,
+				value: HomeComponentValue::MangaChapterList {
+					page_size: Some(10),
+					entries,
+					listing: Some(Listing {
+						id: "recent".into(),
+						name: "Recent Uploads".into(),
+						..Default::default()
+					}),
+				},
+			}],
+		})
+	}
+}
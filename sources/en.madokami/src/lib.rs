@@ -1,8 +1,9 @@
 #![no_std]
 use aidoku::{
-	BasicLoginHandler, Chapter, DeepLinkHandler, DeepLinkResult, FilterValue, ImageRequestProvider,
-	Manga, MangaPageResult, MangaStatus, Page, PageContent, PageContext, Result, Source,
-	alloc::{string::String, vec::Vec},
+	BasicLoginHandler, Chapter, DeepLinkHandler, DeepLinkResult, DynamicListings, FilterValue,
+	Home, ImageRequestProvider, Listing, ListingProvider, Manga, MangaPageResult, MangaStatus,
+	Page, PageContent, PageContext, Result, Source,
+	alloc::{string::String, vec, vec::Vec},
 	helpers::uri::{QueryParameters, decode_uri},
 	imports::{
 		net::Request,
@@ -12,10 +13,16 @@ use aidoku::{
 };
 
 mod auth;
+mod browse;
 mod helpers;
+mod home;
 use auth::AuthedRequest;
 
 const BASE_URL: &str = "https://manga.madokami.al";
+const PAGE_SIZE: usize = 50;
+
+// other top level directories, browsed as a flat list of series
+const FLAT_DIRECTORIES: &[(&str, &str)] = &[("/Raws", "Raws"), ("/Novels", "Novels")];
 
 struct Madokami;
 
@@ -165,11 +172,19 @@ impl Source for Madokami {
 						let href = el.select_first("td:nth-child(6) a")?.attr("href")?;
 						let key = href[href.find("/reader")?..].into();
 						let url = format!("{BASE_URL}{key}");
+						let title = el
+							.select_first("td:nth-child(1) a")
+							.and_then(|el| el.text());
+						let info = title
+							.as_deref()
+							.map(helpers::parse_archive_filename)
+							.unwrap_or_default();
 						Some(Chapter {
 							key,
-							title: el
-								.select_first("td:nth-child(1) a")
-								.and_then(|el| el.text()),
+							title,
+							volume_number: info.volume_number,
+							chapter_number: info.chapter_number,
+							scanlators: (!info.scanlators.is_empty()).then_some(info.scanlators),
 							date_uploaded: el
 								.select_first("td:nth-child(3)")
 								.and_then(|el| el.text())
@@ -229,6 +244,85 @@ impl Source for Madokami {
 	}
 }
 
+impl ListingProvider for Madokami {
+	fn get_manga_list(&self, listing: Listing, page: i32) -> Result<MangaPageResult> {
+		if listing.id == "recent" {
+			let html = browse::get_html("/recent")?;
+			return Ok(MangaPageResult {
+				entries: browse::parse_recent(&html)
+					.into_iter()
+					.map(|entry| entry.manga)
+					.collect(),
+				has_next_page: false,
+			});
+		}
+
+		let page = usize::try_from(page.max(1) - 1).unwrap_or_default();
+		let directories = browse::parse_directory(&browse::get_html(&listing.id)?);
+
+		if listing.id.starts_with("/Manga/") {
+			// manga directories are nested by prefix, e.g. /Manga/F/FI/FIGH/<series>,
+			// so each page covers one second level directory
+			let Some(directory) = directories.get(page) else {
+				return Ok(MangaPageResult::default());
+			};
+			let prefixes = browse::parse_directory(&browse::get_html(&directory.key)?);
+			let requests = prefixes
+				.iter()
+				.map(|prefix| Ok(Request::get(format!("{BASE_URL}{}", prefix.key))?.authed()?))
+				.collect::<Result<Vec<_>>>()?;
+			let entries = Request::send_all(requests)
+				.into_iter()
+				.flatten()
+				.filter_map(|response| response.get_html().ok())
+				.flat_map(|html| browse::parse_directory(&html))
+				.collect();
+			Ok(MangaPageResult {
+				entries,
+				has_next_page: page + 1 < directories.len(),
+			})
+		} else {
+			let start = page * PAGE_SIZE;
+			Ok(MangaPageResult {
+				has_next_page: start + PAGE_SIZE < directories.len(),
+				entries: directories
+					.into_iter()
+					.skip(start)
+					.take(PAGE_SIZE)
+					.collect(),
+			})
+		}
+	}
+}
+
+impl DynamicListings for Madokami {
+	fn get_dynamic_listings(&self) -> Result<Vec<Listing>> {
+		let mut listings = vec![Listing {
+			id: "recent".into(),
+			name: "Recent Uploads".into(),
+			..Default::default()
+		}];
+		// one listing per letter directory under /Manga
+		let html = browse::get_html("/Manga")?;
+		listings.extend(
+			browse::parse_directory(&html)
+				.into_iter()
+				.filter(|dir| !dir.title.starts_with('_'))
+				.map(|dir| Listing {
+					name: format!("Manga: {}", dir.title),
+					id: dir.key,
+					..Default::default()
+				}),
+		);
+		listings.extend(FLAT_DIRECTORIES.iter().map(|(id, name)| Listing {
+			id: (*id).into(),
+			name: (*name).into(),
+			..Default::default()
+		}));
+		Ok(listings)
+	}
+}
+
 impl ImageRequestProvider for Madokami {
 	fn get_image_request(&self, url: String, _context: Option<PageContext>) -> Result<Request> {
 		Ok(Request::get(url)?
@@ -263,4 +357,11 @@ impl DeepLinkHandler for Madokami {
 	}
 }
 
-register_source!(Madokami, BasicLoginHandler, DeepLinkHandler);
+register_source!(
+	Madokami,
+	Home,
+	ListingProvider,
+	DynamicListings,
+	BasicLoginHandler,
+	DeepLinkHandler
+);
@@ -1,5 +1,5 @@
 #![no_std]
-use aidoku::{prelude::*, Source};
+use aidoku::{Source, prelude::*};
 use iken::{Iken, Impl, Params};
 
 const BASE_URL: &str = "https://magustoon.org";
@@ -21,4 +21,10 @@ impl Impl for MagusManga {
 	}
 }
 
-register_source!(Iken<MagusManga>, Home, DeepLinkHandler);
+register_source!(
+	Iken<MagusManga>,
+	ListingProvider,
+	Home,
+	DynamicFilters,
+	DeepLinkHandler
+);
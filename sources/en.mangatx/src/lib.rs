@@ -14,6 +14,7 @@ impl Impl for MangaTx {
 	fn params(&self) -> Params {
 		Params {
 			base_url: BASE_URL.into(),
+			fallback_filters: include_str!("../res/fallback_filters.json").into(),
 			manga_url_directory: "/manga-list".into(),
 			date_format: "dd-MM-yyyy".into(),
 			mark_all_nsfw: true,
@@ -26,5 +27,7 @@ register_source!(
 	MangaThemesia<MangaTx>,
 	Home,
 	ImageRequestProvider,
-	DeepLinkHandler
+	PageImageProcessor,
+	DeepLinkHandler,
+	DynamicFilters
 );
@@ -1,5 +1,5 @@
 #![no_std]
-use aidoku::{prelude::*, Source};
+use aidoku::{Source, prelude::*};
 use iken::{Iken, Impl, Params};
 
 const BASE_URL: &str = "https://nyxscans.com";
@@ -21,4 +21,10 @@ impl Impl for NyxScans {
 	}
 }
 
-register_source!(Iken<NyxScans>, Home, DeepLinkHandler);
+register_source!(
+	Iken<NyxScans>,
+	ListingProvider,
+	Home,
+	DynamicFilters,
+	DeepLinkHandler
+);
@@ -19,16 +19,6 @@ impl Impl for QiScans {
 		Params {
 			base_url: BASE_URL.into(),
 			api_url: Some(API_URL.into()),
-			get_sort_value: |index| {
-				match index {
-					0 => "createdAt",
-					1 => "updatedAt",
-					2 => "totalViews",
-					3 => "postTitle",
-					_ => "createdAt",
-				}
-				.into()
-			},
 			..Default::default()
 		}
 	}
@@ -60,4 +50,10 @@ impl Impl for QiScans {
 	}
 }
 
-register_source!(Iken<QiScans>, Home, DeepLinkHandler);
+register_source!(
+	Iken<QiScans>,
+	ListingProvider,
+	Home,
+	DynamicFilters,
+	DeepLinkHandler
+);
@@ -14,6 +14,7 @@ impl Impl for RizzFables {
 	fn params(&self) -> Params {
 		Params {
 			base_url: BASE_URL.into(),
+			fallback_filters: include_str!("../res/fallback_filters.json").into(),
 			manga_url_directory: "/series".into(),
 			date_format: "dd MMM yyyy".into(),
 			..Default::default()
@@ -42,5 +43,7 @@ register_source!(
 	MangaThemesia<RizzFables>,
 	Home,
 	ImageRequestProvider,
-	DeepLinkHandler
+	PageImageProcessor,
+	DeepLinkHandler,
+	DynamicFilters
 );
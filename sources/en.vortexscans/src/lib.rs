@@ -1,5 +1,5 @@
 #![no_std]
-use aidoku::{prelude::*, Source};
+use aidoku::{Source, prelude::*};
 use iken::{Iken, Impl, Params};
 
 const BASE_URL: &str = "https://vortexscans.org";
@@ -21,4 +21,10 @@ impl Impl for VortexScans {
 	}
 }
 
-register_source!(Iken<VortexScans>, Home, DeepLinkHandler);
+register_source!(
+	Iken<VortexScans>,
+	ListingProvider,
+	Home,
+	DynamicFilters,
+	DeepLinkHandler
+);
@@ -1,5 +1,5 @@
 #![no_std]
-use aidoku::{prelude::*, Source};
+use aidoku::{Source, prelude::*};
 use iken::{Iken, Impl, Params};
 
 const BASE_URL: &str = "https://eternalmangas.org";
@@ -16,9 +16,16 @@ impl Impl for MagusManga {
 		Params {
 			base_url: BASE_URL.into(),
 			api_url: Some(API_URL.into()),
+			genre_filter_title: "Géneros".into(),
 			..Default::default()
 		}
 	}
 }
 
-register_source!(Iken<MagusManga>, Home, DeepLinkHandler);
+register_source!(
+	Iken<MagusManga>,
+	ListingProvider,
+	Home,
+	DynamicFilters,
+	DeepLinkHandler
+);
@@ -14,6 +14,7 @@ impl Impl for Sushiscan {
 	fn params(&self) -> Params {
 		Params {
 			base_url: BASE_URL.into(),
+			fallback_filters: include_str!("../res/fallback_filters.json").into(),
 			manga_url_directory: "/catalogue".into(),
 			date_format: "d MMMM yyyy".into(),
 			date_locale: "fr_FR".into(),
@@ -26,5 +27,7 @@ register_source!(
 	MangaThemesia<Sushiscan>,
 	Home,
 	ImageRequestProvider,
-	DeepLinkHandler
+	PageImageProcessor,
+	DeepLinkHandler,
+	DynamicFilters
 );
@@ -14,6 +14,7 @@ impl Impl for Kanzenin {
 	fn params(&self) -> Params {
 		Params {
 			base_url: BASE_URL.into(),
+			fallback_filters: include_str!("../res/fallback_filters.json").into(),
 			date_locale: "id".into(),
 			..Default::default()
 		}
@@ -24,5 +25,7 @@ register_source!(
 	MangaThemesia<Kanzenin>,
 	Home,
 	ImageRequestProvider,
-	DeepLinkHandler
+	PageImageProcessor,
+	DeepLinkHandler,
+	DynamicFilters
 );
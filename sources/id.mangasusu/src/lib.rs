@@ -14,6 +14,7 @@ impl Impl for Mangasusu {
 	fn params(&self) -> Params {
 		Params {
 			base_url: BASE_URL.into(),
+			fallback_filters: include_str!("../res/fallback_filters.json").into(),
 			manga_url_directory: "/komik".into(),
 			mark_all_nsfw: true,
 			..Default::default()
@@ -25,5 +26,7 @@ register_source!(
 	MangaThemesia<Mangasusu>,
 	Home,
 	ImageRequestProvider,
-	DeepLinkHandler
+	PageImageProcessor,
+	DeepLinkHandler,
+	DynamicFilters
 );
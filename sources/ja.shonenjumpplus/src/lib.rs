@@ -1,17 +1,94 @@
 #![no_std]
 use aidoku::{
-	alloc::{vec, Vec},
-	imports::{html::Document, net::Request},
+	alloc::{vec, String, Vec},
+	imports::{
+		html::{Document, Element},
+		net::Request,
+		std::send_partial_result,
+	},
 	prelude::*,
-	HomeComponent, HomeLayout, Link, Listing, MangaPageResult, Result, Source,
+	DeepLinkResult, FilterValue, HomeComponent, HomeLayout, Link, Listing, ListingKind, Manga,
+	MangaPageResult, Result, Source,
 };
 use gigaviewer::{GigaViewer, Impl, Params};
 
+mod rookie;
+
 const BASE_URL: &str = "https://shonenjumpplus.com";
 const CDN_URL: &str = "https://cdn-ak-img.shonenjumpplus.com";
 
+const WEEKDAYS: [(&str, &str); 7] = [
+	("monday", "月曜日"),
+	("tuesday", "火曜日"),
+	("wednesday", "水曜日"),
+	("thursday", "木曜日"),
+	("friday", "金曜日"),
+	("saturday", "土曜日"),
+	("sunday", "日曜日"),
+];
+
 struct ShonenJumpPlus;
 
+// an impl without any overrides, used to fall back to the template behavior
+struct Defaults;
+
+impl Impl for Defaults {
+	fn new() -> Self {
+		Self
+	}
+
+	fn params(&self) -> Params {
+		Params::default()
+	}
+}
+
+// finds the weekday of a daily section from its class names, falling back to its header
+fn weekday_title(element: &Element) -> Option<String> {
+	let class = element.attr("class").unwrap_or_default().to_lowercase();
+	WEEKDAYS
+		.iter()
+		.find(|(day, _)| class.contains(day))
+		.map(|(_, title)| String::from(*title))
+		.or_else(|| {
+			element
+				.select_first(".daily-header, .daily-title")
+				.and_then(|e| e.text())
+		})
+}
+
+// parses the series items in a daily section
+fn parse_daily_series(day: &Element) -> Vec<Link> {
+	day.select(".daily-series-item")
+		.map(|items| {
+			items
+				.filter_map(|item| {
+					let key = item
+						.select_first("a")?
+						.attr("href")?
+						.strip_prefix(BASE_URL)
+						.map(String::from)?;
+					let title = item.select_first("h2")?.text()?;
+					let cover = item.select_first("img").and_then(|img| img.attr("src"));
+					let authors = item
+						.select_first(".daily-series-author")
+						.and_then(|el| el.text())
+						.map(|text| text.split('/').map(String::from).collect());
+					Some(
+						Manga {
+							key,
+							title,
+							cover,
+							authors,
+							..Default::default()
+						}
+						.into(),
+					)
+				})
+				.collect()
+		})
+		.unwrap_or_default()
+}
+
 impl Impl for ShonenJumpPlus {
 	fn new() -> Self {
 		Self
@@ -26,12 +103,79 @@ impl Impl for ShonenJumpPlus {
 		}
 	}
 
+	fn get_search_manga_list(
+		&self,
+		params: &Params,
+		query: Option<String>,
+		page: i32,
+		filters: Vec<FilterValue>,
+	) -> Result<MangaPageResult> {
+		let search_rookie = filters.iter().any(|filter| {
+			matches!(filter, FilterValue::Select { id, value } if id == "site" && value == "rookie")
+		});
+		if search_rookie {
+			let mut result =
+				Defaults.get_search_manga_list(&rookie::params(), query, page, filters)?;
+			result.entries = result
+				.entries
+				.into_iter()
+				.map(rookie::absolute_manga)
+				.collect();
+			Ok(result)
+		} else {
+			Defaults.get_search_manga_list(params, query, page, filters)
+		}
+	}
+
+	fn get_manga_update(
+		&self,
+		params: &Params,
+		manga: Manga,
+		needs_details: bool,
+		needs_chapters: bool,
+	) -> Result<Manga> {
+		let Some(path) = manga.key.strip_prefix(rookie::ROOKIE_URL) else {
+			return Defaults.get_manga_update(params, manga, needs_details, needs_chapters);
+		};
+
+		let rookie_params = rookie::params();
+		let relative_manga = Manga {
+			key: path.into(),
+			..manga.clone()
+		};
+		let mut new_manga = manga;
+
+		// details and chapters are fetched separately so that the partial result keeps the absolute key
+		if needs_details {
+			let details =
+				Defaults.get_manga_update(&rookie_params, relative_manga.clone(), true, false)?;
+			new_manga.copy_from(rookie::absolute_manga(details));
+
+			if needs_chapters {
+				send_partial_result(&new_manga);
+			}
+		}
+
+		if needs_chapters {
+			new_manga.chapters = Defaults
+				.get_manga_update(&rookie_params, relative_manga, false, true)?
+				.chapters
+				.map(|chapters| chapters.into_iter().map(rookie::absolute_chapter).collect());
+		}
+
+		Ok(new_manga)
+	}
+
 	fn get_manga_list(
 		&self,
 		_params: &Params,
 		listing: Listing,
 		_page: i32,
 	) -> Result<MangaPageResult> {
+		if let Some(path) = listing.id.strip_prefix("rookie/") {
+			return rookie::get_manga_list(path);
+		}
+
 		let item_selector: &str;
 		let title_selector: &str;
 		let cover_selector: &str;
@@ -46,7 +190,7 @@ impl Impl for ShonenJumpPlus {
 				cover_attr = "data-src";
 				authors_selector = Some(".series-list-author");
 			}
-			_ => return Impl::get_manga_list(self, _params, listing, _page),
+			_ => return Defaults.get_manga_list(_params, listing, _page),
 		}
 
 		let base_url = self.params().base_url;
@@ -105,46 +249,90 @@ impl Impl for ShonenJumpPlus {
 		.collect();
 		let total_ranking = parse_home_section(&html, ".total-ranking-list-sp a");
 		let free_campaign = parse_home_section(&html, ".free-campaign a");
+		let rookie = rookie::get_manga_list("ranking")
+			.map(|result| {
+				result
+					.entries
+					.into_iter()
+					.map(|manga| manga.into())
+					.collect()
+			})
+			.unwrap_or_default();
+
+		// the first daily section is today's, which is already shown as the ranking
+		let weekdays = html
+			.select(".daily")
+			.map(|days| {
+				days.skip(1)
+					.filter_map(|day| {
+						let title = weekday_title(&day)?;
+						let entries = parse_daily_series(&day);
+						if entries.is_empty() {
+							return None;
+						}
+						Some(HomeComponent {
+							title: Some(title),
+							subtitle: None,
+							value: aidoku::HomeComponentValue::Scroller {
+								entries,
+								listing: None,
+							},
+						})
+					})
+					.collect::<Vec<_>>()
+			})
+			.unwrap_or_default();
 
-		Ok(HomeLayout {
-			components: vec![
-				HomeComponent {
-					title: Some("今日のランキング".into()),
-					subtitle: ranking_subtitle,
-					value: aidoku::HomeComponentValue::MangaList {
-						ranking: true,
-						page_size: Some(3),
-						entries: ranking,
-						listing: None,
-					},
+		let mut components = vec![HomeComponent {
+			title: Some("今日のランキング".into()),
+			subtitle: ranking_subtitle,
+			value: aidoku::HomeComponentValue::MangaList {
+				ranking: true,
+				page_size: Some(3),
+				entries: ranking,
+				listing: None,
+			},
+		}];
+		components.extend(weekdays);
+		components.extend([
+			HomeComponent {
+				title: Some("総合ランキング".into()),
+				subtitle: Some("今話題の作品をチェック！".into()),
+				value: aidoku::HomeComponentValue::Scroller {
+					entries: total_ranking,
+					listing: None,
 				},
-				HomeComponent {
-					title: Some("総合ランキング".into()),
-					subtitle: Some("今話題の作品をチェック！".into()),
-					value: aidoku::HomeComponentValue::Scroller {
-						entries: total_ranking,
-						listing: None,
-					},
+			},
+			HomeComponent {
+				title: Some("無料キャンペーン・復刻連載".into()),
+				subtitle: Some("話題作や名作が今だけ無料の大公開！".into()),
+				value: aidoku::HomeComponentValue::Scroller {
+					entries: free_campaign,
+					listing: None,
 				},
-				HomeComponent {
-					title: Some("無料キャンペーン・復刻連載".into()),
-					subtitle: Some("話題作や名作が今だけ無料の大公開！".into()),
-					value: aidoku::HomeComponentValue::Scroller {
-						entries: free_campaign,
-						listing: None,
-					},
+			},
+			HomeComponent {
+				title: Some("ジャンプルーキー！".into()),
+				subtitle: Some("才能溢れる投稿作が読み放題！".into()),
+				value: aidoku::HomeComponentValue::Scroller {
+					entries: rookie,
+					listing: Some(Listing {
+						id: "rookie/ranking".into(),
+						name: "ジャンプルーキー！ランキング".into(),
+						kind: ListingKind::List,
+					}),
 				},
-				// rookies are on a different subdomain, which we aren't supporting (idek if it's gigaviewer)
-				// HomeComponent {
-				//     title: Some("ジャンプルーキー！".into()),
-				//     subtitle: Some("才能溢れる投稿作が読み放題！".into()),
-				//     value: aidoku::HomeComponentValue::Scroller {
-				//         entries: rookie,
-				//         listing: None,
-				//     },
-				// },
-			],
-		})
+			},
+		]);
+
+		Ok(HomeLayout { components })
+	}
+
+	fn handle_deep_link(&self, params: &Params, url: String) -> Result<Option<DeepLinkResult>> {
+		if rookie::is_rookie_key(&url) {
+			return Ok(rookie::handle_deep_link(&url));
+		}
+		Defaults.handle_deep_link(params, url)
 	}
 }
 
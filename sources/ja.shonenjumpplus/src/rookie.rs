@@ -0,0 +1,87 @@
+use aidoku::{
+	alloc::{String, Vec},
+	imports::net::Request,
+	prelude::*,
+	Chapter, DeepLinkResult, Manga, MangaPageResult, Result,
+};
+use gigaviewer::Params;
+
+// jump rookie! is hosted on its own gigaviewer site, so its keys are stored as absolute urls
+pub const ROOKIE_URL: &str = "https://rookie.shonenjumpplus.com";
+
+pub fn params() -> Params {
+	Params {
+		base_url: ROOKIE_URL.into(),
+		..Default::default()
+	}
+}
+
+pub fn is_rookie_key(key: &str) -> bool {
+	key.starts_with(ROOKIE_URL)
+}
+
+// converts a key relative to the rookie site into an absolute one
+pub fn absolute_key(key: String) -> String {
+	if key.starts_with("http") {
+		key
+	} else {
+		format!("{ROOKIE_URL}{key}")
+	}
+}
+
+pub fn absolute_manga(manga: Manga) -> Manga {
+	Manga {
+		key: absolute_key(manga.key),
+		..manga
+	}
+}
+
+pub fn absolute_chapter(chapter: Chapter) -> Chapter {
+	Chapter {
+		key: absolute_key(chapter.key),
+		..chapter
+	}
+}
+
+pub fn get_manga_list(path: &str) -> Result<MangaPageResult> {
+	let params = params();
+	let html = Request::get(format!("{ROOKIE_URL}/{path}"))?.html()?;
+
+	let entries = gigaviewer::parser::parse_response(
+		&html,
+		ROOKIE_URL,
+		&params.search_item_selector,
+		&params.search_item_title_selector,
+		"img",
+		"src",
+		None,
+		None,
+	)
+	.into_iter()
+	.map(absolute_manga)
+	.collect::<Vec<_>>();
+
+	Ok(MangaPageResult {
+		entries,
+		has_next_page: false,
+	})
+}
+
+pub fn handle_deep_link(url: &str) -> Option<DeepLinkResult> {
+	let path = url.strip_prefix(ROOKIE_URL)?;
+	let path = path.split(['?', '#']).next().unwrap_or_default();
+	let key = format!("{ROOKIE_URL}{path}");
+
+	if path.starts_with("/series/") {
+		// ex: https://rookie.shonenjumpplus.com/series/3269754496306260262
+		Some(DeepLinkResult::Manga { key })
+	} else if path.starts_with("/episode/") {
+		// like the main site, an episode page can also be used as the manga key
+		Some(DeepLinkResult::Chapter {
+			manga_key: key.clone(),
+			key,
+		})
+	} else {
+		None
+	}
+}
@@ -1,7 +1,8 @@
 #![no_std]
 use aidoku::{
-	Chapter, ContentRating, DeepLinkHandler, DeepLinkResult, FilterValue, Manga, MangaPageResult,
-	NotificationHandler, Page, PageContent, Result, Source, Viewer,
+	Chapter, ContentRating, DeepLinkHandler, DeepLinkResult, FilterValue, ImageRef, ImageResponse,
+	Manga, MangaPageResult, NotificationHandler, Page, PageContent, PageContext,
+	PageImageProcessor, Result, Source, Viewer,
 	alloc::{String, Vec, string::ToString, vec},
 	imports::{net::Request, std::parse_date},
 	prelude::*,
@@ -24,6 +25,19 @@ struct Cubari {
 	storage: RefCell<Option<RemoteStorage>>,
 }
 
+impl Cubari {
+	// writes the series back to remote storage, if logged in
+	fn sync_series(&self, manga: &Manga, chapter: &str) {
+		if !settings::get_sync_history() {
+			return;
+		}
+		if let Some(ref storage) = *self.storage.borrow() {
+			// failing to sync shouldn't prevent the series from loading
+			let _ = storage.put_series(manga, chapter);
+		}
+	}
+}
+
 impl Source for Cubari {
 	fn new() -> Self {
 		Self {
@@ -246,7 +260,15 @@ This source locally tracks and saves any series found, which can be disabled in
 				.get(group)
 				.ok_or(error!("Missing `{group}` group field"))?;
 
-			fn parse_page_array(pages: &[Value]) -> Vec<Page> {
+			// the first page carries the series and chapter, which are synced to remote storage
+			// once it's shown instead of here, since page lists are also fetched for downloads
+			let mut context = PageContext::new();
+			context.insert("series".into(), manga.key.clone());
+			context.insert("title".into(), manga.title.clone());
+			context.insert("chapter".into(), chapter_id.into());
+
+			fn parse_page_array(pages: &[Value], context: PageContext) -> Vec<Page> {
+				let mut context = Some(context);
 				pages
 					.iter()
 					.filter_map(|value| {
@@ -257,8 +279,12 @@ This source locally tracks and saves any series found, which can be disabled in
 						} else {
 							return None;
 						};
+						let url = helpers::img_url_handler(url);
 						Some(Page {
-							content: PageContent::url(helpers::img_url_handler(url)),
+							content: match context.take() {
+								Some(context) => PageContent::url_context(url, context),
+								None => PageContent::url(url),
+							},
 							..Default::default()
 						})
 					})
@@ -266,13 +292,13 @@ This source locally tracks and saves any series found, which can be disabled in
 			}
 
 			if let Some(array) = pages.as_array() {
-				Ok(parse_page_array(array))
+				Ok(parse_page_array(array, context))
 			} else if let Some(endpoint) = pages.as_str() {
 				let value: Value =
 					Request::get(format!("https://cubari.moe{endpoint}"))?.json_owned()?;
 				value
 					.as_array()
-					.map(|array| parse_page_array(array))
+					.map(|array| parse_page_array(array, context))
 					.ok_or(error!("Invalid result from endpoint {endpoint}"))
 			} else {
 				bail!("Invalid `pages` type")
@@ -303,6 +329,19 @@ impl NotificationHandler for Cubari {
 				database::delete_all_manga();
 				settings::set_history_revision("");
 			}
+			"deleteRemoteHistory" => {
+				if let Some(ref storage) = *self.storage.borrow() {
+					let _ = storage.delete_all_series();
+				}
+			}
+			"deleteRemoteSeries" => {
+				let slug = url_to_slug(settings::get_remote_series());
+				if let Some(ref storage) = *self.storage.borrow()
+					&& slug.contains('/')
+				{
+					let _ = storage.delete_series(&slug);
+				}
+			}
 			settings::ADDRESS_KEY => {
 				settings::set_token("");
 				let address = settings::get_address();
@@ -352,4 +391,29 @@ impl NotificationHandler for Cubari {
 	}
 }
 
-register_source!(Cubari, DeepLinkHandler, NotificationHandler);
+impl PageImageProcessor for Cubari {
+	fn process_page_image(
+		&self,
+		response: ImageResponse,
+		context: Option<PageContext>,
+	) -> Result<ImageRef> {
+		if let Some(context) = context
+			&& let (Some(key), Some(chapter)) = (context.get("series"), context.get("chapter"))
+		{
+			let manga = Manga {
+				key: key.clone(),
+				title: context.get("title").cloned().unwrap_or_default(),
+				..Default::default()
+			};
+			self.sync_series(&manga, chapter);
+		}
+		Ok(response.image)
+	}
+}
+
+register_source!(
+	Cubari,
+	DeepLinkHandler,
+	NotificationHandler,
+	PageImageProcessor
+);
@@ -1,14 +1,23 @@
 use crate::{database, settings};
 use aidoku::{
 	Manga, Result,
-	alloc::{string::String, vec::Vec},
-	imports::net::Request,
+	alloc::{collections::btree_map::BTreeMap, string::String, vec::Vec},
+	imports::{
+		net::{HttpMethod, Request, Response},
+		std::current_date,
+	},
 	prelude::*,
 };
+use core::cell::RefCell;
+use serde_json::{Value, json};
+
+const SERIES_CONTEXT: &str = "http://remotestorage.io/spec/modules/cubari/series";
 
 pub struct RemoteStorage {
 	url: String,
 	token: String,
+	// series documents and their etags, as last fetched or written
+	series: RefCell<BTreeMap<String, (Value, String)>>,
 }
 
 impl RemoteStorage {
@@ -18,7 +27,11 @@ impl RemoteStorage {
 		if url.is_empty() || token.is_empty() {
 			return None;
 		}
-		Some(Self { url, token })
+		Some(Self {
+			url,
+			token,
+			series: RefCell::new(BTreeMap::new()),
+		})
 	}
 
 	pub fn get_all_series(&self) -> Result<Vec<Manga>> {
@@ -49,15 +62,7 @@ impl RemoteStorage {
 			// database is already up to date with remote storage
 			Ok(series_list)
 		} else {
-			let json: serde_json::Value = Request::get(format!("{}/cubari/series/", self.url))?
-				.header("Authorization", &format!("Bearer {}", self.token))
-				.json_owned()?;
-			let items = json
-				.get("items")
-				.and_then(|v| v.as_object())
-				.ok_or(error!("Missing `items` field"))?;
-
-			for id in items.keys() {
+			for id in self.series_ids()? {
 				let key = id.replace('-', "/");
 				if !series_list.iter().any(|series| series.key == key) {
 					// fetch missing series data
@@ -91,4 +96,170 @@ impl RemoteStorage {
 			Ok(series_list)
 		}
 	}
+
+	fn series_ids(&self) -> Result<Vec<String>> {
+		let json: serde_json::Value = Request::get(format!("{}/cubari/series/", self.url))?
+			.header("Authorization", &format!("Bearer {}", self.token))
+			.json_owned()?;
+		let items = json
+			.get("items")
+			.and_then(|v| v.as_object())
+			.ok_or(error!("Missing `items` field"))?;
+		Ok(items.keys().cloned().collect())
+	}
+
+	// remote storage series ids use a dash instead of a slash, e.g. `imgur-hYhqG7b`
+	fn series_url(&self, key: &str) -> String {
+		format!("{}/cubari/series/{}", self.url, key.replacen('/', "-", 1))
+	}
+
+	fn authed(&self, request: Request) -> Request {
+		request.header("Authorization", &format!("Bearer {}", self.token))
+	}
+
+	fn check_auth(response: &Response) -> Result<()> {
+		if response.status_code() == 401 {
+			settings::set_token("");
+			bail!("Unauthorized: Log in again to access history")
+		}
+		Ok(())
+	}
+
+	// fetches a stored series document and its etag, if it exists
+	// the last fetched or written document is reused, since writes are checked with its etag
+	fn get_series(&self, key: &str) -> Result<Option<(Value, String)>> {
+		if let Some(series) = self.series.borrow().get(key) {
+			return Ok(Some(series.clone()));
+		}
+		let mut response = self.authed(Request::get(self.series_url(key))?).send()?;
+		Self::check_auth(&response)?;
+		if response.status_code() == 404 {
+			return Ok(None);
+		}
+		let etag = response.get_header("ETag").unwrap_or_default();
+		let json: Value = response.get_json()?;
+		if !etag.is_empty() {
+			self.series
+				.borrow_mut()
+				.insert(key.into(), (json.clone(), etag.clone()));
+		}
+		Ok(Some((json, etag)))
+	}
+
+	/// Writes a series entry to remote storage, marking `chapter` as the last read chapter.
+	///
+	/// Nothing is written if the chapter is already stored. Conflicting writes from other
+	/// devices are detected with `If-Match`, in which case the entry is fetched again and
+	/// merged before retrying once.
+	pub fn put_series(&self, manga: &Manga, chapter: &str) -> Result<()> {
+		for _ in 0..2 {
+			let existing = self.get_series(&manga.key)?;
+			let (source, slug) = manga.key.split_once('/').unwrap_or_default();
+
+			let mut chapters: Vec<Value> = existing
+				.as_ref()
+				.and_then(|(json, _)| json.get("chapters"))
+				.and_then(|v| v.as_array())
+				.cloned()
+				.unwrap_or_default();
+			if chapters.iter().any(|c| c.as_str() == Some(chapter)) {
+				return Ok(());
+			}
+			chapters.push(chapter.into());
+			let existing_str = |field: &str| {
+				existing
+					.as_ref()
+					.and_then(|(json, _)| json.get(field))
+					.and_then(|v| v.as_str())
+					.map(String::from)
+			};
+			// the manga passed when reading may not have its details loaded
+			let title = if manga.title.is_empty() {
+				existing_str("title").unwrap_or_default()
+			} else {
+				manga.title.clone()
+			};
+			let cover = manga
+				.cover
+				.clone()
+				.or_else(|| existing_str("coverUrl"))
+				.unwrap_or_default();
+			let pinned = existing
+				.as_ref()
+				.and_then(|(json, _)| json.get("pinned"))
+				.and_then(|v| v.as_bool())
+				.unwrap_or(false);
+
+			let body = json!({
+				"@context": SERIES_CONTEXT,
+				"slug": slug,
+				"source": source,
+				"url": format!("/read/{}/{}/", source, slug),
+				"title": title,
+				"coverUrl": cover,
+				"chapters": chapters,
+				"pinned": pinned,
+				"timestamp": current_date() * 1000,
+			});
+
+			let mut request = self
+				.authed(Request::new(self.series_url(&manga.key), HttpMethod::Put)?)
+				.header("Content-Type", "application/json")
+				.body(body.to_string());
+			request = match existing {
+				Some((_, etag)) if !etag.is_empty() => request.header("If-Match", &etag),
+				_ => request.header("If-None-Match", "*"),
+			};
+
+			let response = request.send()?;
+			Self::check_auth(&response)?;
+			match response.status_code() {
+				// the entry was changed by another device since it was fetched
+				412 => {
+					self.series.borrow_mut().remove(&manga.key);
+					continue;
+				}
+				code if code >= 400 => bail!("Failed to save series to remote storage"),
+				_ => {
+					let mut series = self.series.borrow_mut();
+					match response.get_header("ETag") {
+						Some(etag) => series.insert(manga.key.clone(), (body, etag)),
+						None => series.remove(&manga.key),
+					};
+					return Ok(());
+				}
+			}
+		}
+		bail!("Series was modified by another device")
+	}
+
+	/// Removes a series entry from remote storage, if it hasn't changed since it was fetched.
+	pub fn delete_series(&self, key: &str) -> Result<()> {
+		let Some((_, etag)) = self.get_series(key)? else {
+			return Ok(());
+		};
+		let mut request = self.authed(Request::new(self.series_url(key), HttpMethod::Delete)?);
+		if !etag.is_empty() {
+			request = request.header("If-Match", &etag);
+		}
+		let response = request.send()?;
+		Self::check_auth(&response)?;
+		self.series.borrow_mut().remove(key);
+		match response.status_code() {
+			412 => bail!("Series was modified by another device"),
+			code if code >= 400 && code != 404 => {
+				bail!("Failed to remove series from remote storage")
+			}
+			_ => Ok(()),
+		}
+	}
+
+	/// Removes every series entry from remote storage.
+	pub fn delete_all_series(&self) -> Result<()> {
+		for id in self.series_ids()? {
+			self.delete_series(&id.replacen('-', "/", 1))?;
+		}
+		settings::set_history_revision("");
+		Ok(())
+	}
 }
@@ -10,6 +10,8 @@ const STORAGE_URL_KEY: &str = "storageUrl";
 const OAUTH_URL_KEY: &str = "rsOAuthUrl";
 const SHOW_HELP_KEY: &str = "showHelp";
 const SAVE_SERIES_KEY: &str = "saveSeries";
+const SYNC_HISTORY_KEY: &str = "syncHistory";
+const REMOTE_SERIES_KEY: &str = "remoteSeries";
 
 fn set_defaults_string(key: &str, value: &str) {
 	defaults_set(
@@ -61,3 +63,13 @@ pub fn get_show_help() -> bool {
 pub fn get_save_series() -> bool {
 	defaults_get::<bool>(SAVE_SERIES_KEY).unwrap_or(true)
 }
+
+pub fn get_sync_history() -> bool {
+	defaults_get::<bool>(SYNC_HISTORY_KEY).unwrap_or(true)
+}
+
+pub fn get_remote_series() -> String {
+	defaults_get::<String>(REMOTE_SERIES_KEY)
+		.map(|s| s.trim().into())
+		.unwrap_or_default()
+}
@@ -182,3 +182,28 @@ pub fn api_imagedispatch(
 
 	Some((img_url, nl_out))
 }
+
+/// Adds a gallery to the favorite category `favcat` (0-9), or removes it from
+/// favorites when `favcat` is `None`, by submitting the gallery popup form.
+pub fn set_favorite(gid: &str, token: &str, favcat: Option<&str>, cookies: &str) -> Result<()> {
+	let url = format!(
+		"{}/gallerypopups.php?gid={gid}&t={token}&act=addfav",
+		get_base_url()
+	);
+	let (favcat, apply) = match favcat {
+		Some(favcat) => (favcat, "Add+to+Favorites"),
+		None => ("favdel", "Apply+Changes"),
+	};
+	let body = format!("favcat={favcat}&favnote=&apply={apply}&update=1");
+	let resp = Request::post(&url)?
+		.header("Content-Type", "application/x-www-form-urlencoded")
+		.header("Cookie", cookies)
+		.header("User-Agent", USER_AGENT)
+		.header("Referer", &url)
+		.body(body.as_bytes())
+		.send()?;
+	if resp.status_code() != 200 {
+		bail!("Failed to update favorites. Make sure you are logged in.");
+	}
+	Ok(())
+}
@@ -1,9 +1,9 @@
 #![no_std]
 
 use aidoku::{
-	Chapter, DeepLinkHandler, DeepLinkResult, DynamicListings, FilterValue, ImageRequestProvider,
-	Listing, ListingProvider, Manga, MangaPageResult, Page, PageContent, PageContext, Result,
-	Source,
+	ButtonSetting, Chapter, DeepLinkHandler, DeepLinkResult, DynamicListings, DynamicSettings,
+	FilterValue, GroupSetting, ImageRequestProvider, Listing, ListingProvider, Manga,
+	MangaPageResult, NotificationHandler, Page, PageContent, PageContext, Result, Setting, Source,
 	alloc::{String, Vec, string::ToString, vec},
 	helpers::uri::{QueryParameters, encode_uri_component},
 	imports::{error::AidokuError, net::Request, std::parse_date},
@@ -300,6 +300,12 @@ impl Source for EHentai {
 		if needs_details && let Some(g) = gallery {
 			let updated: Manga = g.into();
 			manga.copy_from(updated);
+
+			// remembered for the favorite buttons in settings, since only the user opens details
+			if is_logged_in() {
+				set_last_gallery(&manga.key, &manga.title);
+				set_last_gallery_favorite(parse_favorite_state(&html));
+			}
 		}
 
 		if needs_chapters {
@@ -427,6 +433,34 @@ impl ListingProvider for EHentai {
 			return Ok(items_to_manga_page(items, has_next, &blocklist));
 		}
 
+		// Favorites: cursor-based pagination using the `next` link value, since the
+		// favorites page is ordered by favorited time rather than GID
+		if let Some(favcat) = listing.id.strip_prefix("favorites") {
+			let cursor_id = listing.id.as_str();
+			if page == 1 {
+				clear_page_cursor(cursor_id);
+			}
+			let mut qs = QueryParameters::new();
+			if let Some(favcat) = favcat.strip_prefix('_') {
+				qs.push("favcat", Some(favcat));
+			}
+			if page > 1
+				&& let Some(next) = get_page_cursor(cursor_id)
+			{
+				qs.push("next", Some(&next));
+			}
+			let url = format!("{base_url}/favorites.php?{qs}");
+			let html = eh_get_html(&url, &cookies, USER_AGENT)?;
+
+			let (items, has_next, _) = parse_gallery_list(&html, &base_url);
+			match parse_next_page_cursor(&html) {
+				Some(next) => set_page_cursor(cursor_id, &next),
+				None => clear_page_cursor(cursor_id),
+			}
+			let blocklist = get_blocklist();
+			return Ok(items_to_manga_page(items, has_next, &blocklist));
+		}
+
 		// For latest/popular: cursor-based pagination using stored last GID
 		let cursor_id = listing.id.as_str();
 		if page == 1 {
@@ -496,12 +530,30 @@ impl DynamicListings for EHentai {
 	fn get_dynamic_listings(&self) -> Result<Vec<Listing>> {
 		let mut listings = Vec::new();
 
-		if !get_ipb_member_id().is_empty() && !get_ipb_pass_hash().is_empty() {
+		if is_logged_in() {
 			listings.push(Listing {
 				id: "watched".into(),
 				name: "Watched".into(),
 				..Default::default()
 			});
+
+			// one listing per favorite category, using the user-defined names
+			let url = format!("{}/favorites.php", get_base_url());
+			if let Ok(html) = eh_get_html(&url, &build_cookie_header(), USER_AGENT) {
+				let categories = parse_favorite_categories(&html);
+				if !categories.is_empty() {
+					listings.push(Listing {
+						id: "favorites".into(),
+						name: "All Favorites".into(),
+						..Default::default()
+					});
+				}
+				listings.extend(categories.into_iter().map(|(favcat, name)| Listing {
+					id: format!("favorites_{favcat}"),
+					name,
+					..Default::default()
+				}));
+			}
 		}
 
 		listings.extend([
@@ -656,11 +708,100 @@ impl DeepLinkHandler for EHentai {
 	}
 }
 
+impl NotificationHandler for EHentai {
+	fn handle_notification(&self, notification: String) {
+		let favcat = match notification.as_str() {
+			"addFavorite" => Some(get_favorite_category()),
+			"removeFavorite" => None,
+			_ => return,
+		};
+		let Some((url, title)) = get_last_gallery() else {
+			return;
+		};
+		let (gid, token) = parse_gallery_id_token(&normalize_gallery_url(&url));
+		let cookies = build_cookie_header();
+		let status = match set_favorite(&gid, &token, favcat.as_deref(), &cookies) {
+			Ok(()) => {
+				// the gallery page shows the category name it was added to
+				let favorite = eh_get_html(&rewrite_domain(&url), &cookies, USER_AGENT)
+					.ok()
+					.and_then(|html| parse_favorite_state(&html));
+				let status = match &favorite {
+					Some(category) => format!("Added {title} to {category}."),
+					None if favcat.is_some() => format!("Added {title} to favorites."),
+					None => format!("Removed {title} from favorites."),
+				};
+				set_last_gallery_favorite(favorite);
+				status
+			}
+			Err(err) => format!("Failed to update favorites for {title}: {err:?}"),
+		};
+		set_favorite_status(status);
+	}
+}
+
+impl DynamicSettings for EHentai {
+	fn get_dynamic_settings(&self) -> Result<Vec<Setting>> {
+		let Some((_, title)) = get_last_gallery().filter(|_| is_logged_in()) else {
+			return Ok(Vec::new());
+		};
+		let favorite = get_last_gallery_favorite();
+
+		let mut items: Vec<Setting> = vec![
+			ButtonSetting {
+				key: "addFavorite".into(),
+				title: if favorite.is_some() {
+					"Move to Default Category".into()
+				} else {
+					"Add to Favorites".into()
+				},
+				notification: Some("addFavorite".into()),
+				refreshes: Some(vec!["settings".into(), "listings".into()]),
+				..Default::default()
+			}
+			.into(),
+		];
+		if favorite.is_some() {
+			items.push(
+				ButtonSetting {
+					key: "removeFavorite".into(),
+					title: "Remove from Favorites".into(),
+					notification: Some("removeFavorite".into()),
+					refreshes: Some(vec!["settings".into(), "listings".into()]),
+					..Default::default()
+				}
+				.into(),
+			);
+		}
+
+		let mut footer = match &favorite {
+			Some(category) => format!("{title} is in {category}."),
+			None => format!("{title} is not in your favorites."),
+		};
+		if let Some(status) = get_favorite_status() {
+			footer = format!("{footer}\n{status}");
+		}
+
+		Ok(vec![
+			GroupSetting {
+				key: "lastGalleryFavorites".into(),
+				title: "Last Opened Gallery".into(),
+				items,
+				footer: Some(footer.into()),
+				..Default::default()
+			}
+			.into(),
+		])
+	}
+}
+
 register_source!(
 	EHentai,
 	Home,
 	ListingProvider,
 	DeepLinkHandler,
 	ImageRequestProvider,
-	DynamicListings
+	DynamicListings,
+	DynamicSettings,
+	NotificationHandler
 );
@@ -155,7 +155,6 @@ fn has_toplist_next_page(html: &Document) -> bool {
 	false
 }
 
-#[allow(dead_code)]
 pub fn parse_next_page_cursor(html: &Document) -> Option<String> {
 	let href = html.select_first("a#dnext")?.attr("href")?;
 	href.split("next=")
@@ -163,6 +162,39 @@ pub fn parse_next_page_cursor(html: &Document) -> Option<String> {
 		.map(|s| s.split('&').next().unwrap_or(s).to_string())
 }
 
+/// Parses the favorite categories from the favorites page as `(favcat, name)` pairs,
+/// using the user-defined category names.
+pub fn parse_favorite_categories(html: &Document) -> Vec<(String, String)> {
+	let Some(els) = html.select("div.ido div.fp") else {
+		return Vec::new();
+	};
+	els.filter_map(|el| {
+		// the "Show All Favorites" entry has no favcat
+		let favcat = el
+			.attr("onclick")
+			.and_then(|onclick| extract_between(&onclick, "favcat=", "'").map(String::from))
+			.filter(|s| !s.is_empty() && s.chars().all(|c| c.is_ascii_digit()))?;
+		let name = el
+			.select("div")
+			.and_then(|divs| divs.last())
+			.and_then(|div| div.text())
+			.map(|s| s.trim().to_string())
+			.filter(|s| !s.is_empty())
+			.unwrap_or_else(|| format!("Favorites {favcat}"));
+		Some((favcat, name))
+	})
+	.collect()
+}
+
+/// Parses the favorite category a gallery is in from its page, or `None` when it isn't
+/// in the favorites (or when logged out).
+pub fn parse_favorite_state(html: &Document) -> Option<String> {
+	html.select_first("#favoritelink")
+		.and_then(|el| el.text())
+		.map(|s| s.trim().to_string())
+		.filter(|s| !s.is_empty() && s != "Add to Favorites")
+}
+
 fn parse_item_tags(el: &Element) -> (Vec<String>, Option<String>) {
 	let mut tags: Vec<String> = Vec::new();
 	let mut language: Option<String> = None;
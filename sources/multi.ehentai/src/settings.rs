@@ -11,6 +11,11 @@ const IGNEOUS_KEY: &str = "igneous";
 const TITLE_PREFERENCE_KEY: &str = "titlePreference";
 const LANGUAGES_KEY: &str = "language";
 const BLOCKLIST_KEY: &str = "blocklist";
+const FAVORITE_CATEGORY_KEY: &str = "favoriteCategory";
+const LAST_GALLERY_KEY: &str = "lastGallery";
+const LAST_GALLERY_TITLE_KEY: &str = "lastGalleryTitle";
+const LAST_GALLERY_FAVORITE_KEY: &str = "lastGalleryFavorite";
+const FAVORITE_STATUS_KEY: &str = "favoriteStatus";
 
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub enum TitlePreference {
@@ -36,6 +41,52 @@ pub fn get_ipb_pass_hash() -> String {
 	defaults_get::<String>(IPB_PASS_HASH_KEY).unwrap_or_default()
 }
 
+pub fn is_logged_in() -> bool {
+	!get_ipb_member_id().is_empty() && !get_ipb_pass_hash().is_empty()
+}
+
+/// Returns the favorite category (0-9) galleries are added to by default.
+pub fn get_favorite_category() -> String {
+	defaults_get::<String>(FAVORITE_CATEGORY_KEY).unwrap_or_else(|| "0".into())
+}
+
+/// Returns the url and title of the gallery whose details were last opened, used by the
+/// favorite buttons.
+pub fn get_last_gallery() -> Option<(String, String)> {
+	let url = defaults_get::<String>(LAST_GALLERY_KEY).filter(|s| !s.is_empty())?;
+	let title = defaults_get::<String>(LAST_GALLERY_TITLE_KEY).unwrap_or_default();
+	Some((url, title))
+}
+
+pub fn set_last_gallery(url: &str, title: &str) {
+	if get_last_gallery().is_none_or(|(last_url, _)| last_url != url) {
+		defaults_set(FAVORITE_STATUS_KEY, DefaultValue::Null);
+	}
+	defaults_set(LAST_GALLERY_KEY, DefaultValue::String(url.into()));
+	defaults_set(LAST_GALLERY_TITLE_KEY, DefaultValue::String(title.into()));
+}
+
+/// Returns the favorite category name of the last opened gallery, if it's in the favorites.
+pub fn get_last_gallery_favorite() -> Option<String> {
+	defaults_get::<String>(LAST_GALLERY_FAVORITE_KEY).filter(|s| !s.is_empty())
+}
+
+pub fn set_last_gallery_favorite(favorite: Option<String>) {
+	defaults_set(
+		LAST_GALLERY_FAVORITE_KEY,
+		favorite.map_or(DefaultValue::Null, DefaultValue::String),
+	);
+}
+
+/// Returns the result of the last favorite change, shown in settings.
+pub fn get_favorite_status() -> Option<String> {
+	defaults_get::<String>(FAVORITE_STATUS_KEY)
+}
+
+pub fn set_favorite_status(status: String) {
+	defaults_set(FAVORITE_STATUS_KEY, DefaultValue::String(status));
+}
+
 pub fn get_igneous() -> String {
 	defaults_get::<String>(IGNEOUS_KEY).unwrap_or_default()
 }
@@ -29,12 +29,14 @@ pub const PAGE_SIZE: i32 = 25;
 
 struct Hitomi {
 	gg_cache: RefCell<Option<(GgState, i64)>>,
+	search_cache: RefCell<Option<SearchCache>>,
 }
 
 impl Source for Hitomi {
 	fn new() -> Self {
 		Self {
 			gg_cache: RefCell::new(None),
+			search_cache: RefCell::new(None),
 		}
 	}
 
@@ -191,170 +193,200 @@ impl Source for Hitomi {
 		let use_sort_base =
 			(positive_terms.is_empty() && author_name.is_none()) || !is_default_sort;
 
-		// Parallelize network-bound term queries (ns:tag nozomi requests).
-		let mut positive_results: Vec<Vec<i64>> = Vec::new();
-		// Collect local (plain text) results immediately, and build nozomi requests for network ones
-		let mut local_results: Vec<(usize, Vec<i64>)> = Vec::new();
-		let mut nozomi_requests: Vec<(usize, String)> = Vec::new();
-		for (i, term) in positive_terms.iter().enumerate() {
-			if term.contains(':') {
-				if let Some(url) = nozomi_url_for_ns_tag(term, &lang) {
-					nozomi_requests.push((i, url));
+		// The intersected result set only depends on the query, so it is cached and reused
+		// for later pages until the galleries index is updated.
+		let cache_key = format!(
+			"{sort_nozomi_url}|{}|{}|{}|{}|{}",
+			type_filter.as_deref().unwrap_or_default(),
+			author_name.as_deref().unwrap_or_default(),
+			positive_terms.join(","),
+			negative_terms.join(","),
+			use_sort_base
+		);
+		let index_version = fetch_galleries_index_version().unwrap_or_default();
+
+		let compute_result_ids = || -> Result<Vec<i64>> {
+			// Parallelize network-bound term queries (ns:tag nozomi requests).
+			let mut positive_results: Vec<Vec<i64>> = Vec::new();
+			// Collect local (plain text) results immediately, and build nozomi requests for network ones
+			let mut local_results: Vec<(usize, Vec<i64>)> = Vec::new();
+			let mut nozomi_requests: Vec<(usize, String)> = Vec::new();
+			for (i, term) in positive_terms.iter().enumerate() {
+				if term.contains(':') {
+					if let Some(url) = nozomi_url_for_ns_tag(term, &lang) {
+						nozomi_requests.push((i, url));
+					} else {
+						bail!("Unsupported namespace for term: {term}");
+					}
+				} else if let Some(ids) = search_plain_text(term, &index_version) {
+					local_results.push((i, ids));
 				} else {
-					bail!("Unsupported namespace for term: {term}");
+					bail!("Search failed for term: {term}");
 				}
-			} else if let Some(ids) = search_plain_text(term) {
-				local_results.push((i, ids));
-			} else {
-				bail!("Search failed for term: {term}");
 			}
-		}
-
-		// Insert local results into positive_results at the correct index
-		positive_results.resize(positive_terms.len(), Vec::new());
-		for (i, ids) in local_results {
-			positive_results[i] = ids;
-		}
 
-		// Fire batch requests for nozomi URLs
-		if !nozomi_requests.is_empty() {
-			let mut reqs: Vec<Request> = Vec::new();
-			for (_i, url) in &nozomi_requests {
-				reqs.push(Request::get(url)?.header("Referer", REFERER));
+			// Insert local results into positive_results at the correct index
+			positive_results.resize(positive_terms.len(), Vec::new());
+			for (i, ids) in local_results {
+				positive_results[i] = ids;
 			}
-			let responses = Request::send_all(reqs);
-			// responses length matches reqs; map back to indexes
-			for (resp_i, resp) in responses.into_iter().enumerate() {
-				let idx = nozomi_requests[resp_i].0;
-				match resp {
-					Ok(r) => match r.get_data() {
-						Ok(data) => positive_results[idx] = decode_nozomi(&data),
+
+			// Fire batch requests for nozomi URLs
+			if !nozomi_requests.is_empty() {
+				let mut reqs: Vec<Request> = Vec::new();
+				for (_i, url) in &nozomi_requests {
+					reqs.push(Request::get(url)?.header("Referer", REFERER));
+				}
+				let responses = Request::send_all(reqs);
+				// responses length matches reqs; map back to indexes
+				for (resp_i, resp) in responses.into_iter().enumerate() {
+					let idx = nozomi_requests[resp_i].0;
+					match resp {
+						Ok(r) => match r.get_data() {
+							Ok(data) => positive_results[idx] = decode_nozomi(&data),
+							Err(_) => positive_results[idx] = Vec::new(),
+						},
 						Err(_) => positive_results[idx] = Vec::new(),
-					},
-					Err(_) => positive_results[idx] = Vec::new(),
+					}
 				}
 			}
-		}
-
-		// "author" from detail page: union of artist: and group: nozomis (parallel)
-		if let Some(ref name) = author_name {
-			let artist_term = format!("artist:{name}");
-			let group_term = format!("group:{name}");
-			let mut reqs: Vec<Request> = Vec::new();
-			let mut want_artist = false;
-			let mut want_group = false;
-			if let Some(url) = nozomi_url_for_ns_tag(&artist_term, &lang) {
-				reqs.push(Request::get(&url)?.header("Referer", REFERER));
-				want_artist = true;
-			}
-			if let Some(url) = nozomi_url_for_ns_tag(&group_term, &lang) {
-				reqs.push(Request::get(&url)?.header("Referer", REFERER));
-				want_group = true;
-			}
 
-			let mut artist_vec: Option<Vec<i64>> = None;
-			let mut group_vec: Option<Vec<i64>> = None;
-			if !reqs.is_empty() {
-				let responses = Request::send_all(reqs);
-				let mut resp_iter = responses.into_iter().flatten();
-				if want_artist
-					&& let Some(r) = resp_iter.next()
-					&& let Ok(data) = r.get_data()
-				{
-					artist_vec = Some(decode_nozomi(&data));
+			// "author" from detail page: union of artist: and group: nozomis (parallel)
+			if let Some(ref name) = author_name {
+				let artist_term = format!("artist:{name}");
+				let group_term = format!("group:{name}");
+				let mut reqs: Vec<Request> = Vec::new();
+				let mut want_artist = false;
+				let mut want_group = false;
+				if let Some(url) = nozomi_url_for_ns_tag(&artist_term, &lang) {
+					reqs.push(Request::get(&url)?.header("Referer", REFERER));
+					want_artist = true;
 				}
-				if want_group
-					&& let Some(r) = resp_iter.next()
-					&& let Ok(data) = r.get_data()
-				{
-					group_vec = Some(decode_nozomi(&data));
+				if let Some(url) = nozomi_url_for_ns_tag(&group_term, &lang) {
+					reqs.push(Request::get(&url)?.header("Referer", REFERER));
+					want_group = true;
 				}
-			}
 
-			let mut union: Vec<i64> = match (artist_vec, group_vec) {
-				(Some(a), Some(b)) => {
-					let mut v = a;
-					v.extend(b);
-					v
+				let mut artist_vec: Option<Vec<i64>> = None;
+				let mut group_vec: Option<Vec<i64>> = None;
+				if !reqs.is_empty() {
+					let responses = Request::send_all(reqs);
+					let mut resp_iter = responses.into_iter().flatten();
+					if want_artist
+						&& let Some(r) = resp_iter.next()
+						&& let Ok(data) = r.get_data()
+					{
+						artist_vec = Some(decode_nozomi(&data));
+					}
+					if want_group
+						&& let Some(r) = resp_iter.next()
+						&& let Ok(data) = r.get_data()
+					{
+						group_vec = Some(decode_nozomi(&data));
+					}
 				}
-				(Some(a), None) => a,
-				(None, Some(b)) => b,
-				(None, None) => return Err(error!("No results for author: {name}")),
-			};
-			union.sort_unstable();
-			union.dedup();
-			positive_results.push(union);
-		}
 
-		let mut negative_ids: Vec<i64> = Vec::new();
-		// Parallelize negative_terms requests: local plain-text first, batch nozomi for ns:tags
-		if !negative_terms.is_empty() {
-			let mut neg_local: Vec<Vec<i64>> = Vec::new();
-			let mut neg_reqs: Vec<String> = Vec::new();
-			for term in &negative_terms {
-				if term.contains(':') {
-					if let Some(url) = nozomi_url_for_ns_tag(term, &lang) {
-						neg_reqs.push(url);
+				let mut union: Vec<i64> = match (artist_vec, group_vec) {
+					(Some(a), Some(b)) => {
+						let mut v = a;
+						v.extend(b);
+						v
 					}
-				} else if let Some(ids) = search_plain_text(term) {
-					neg_local.push(ids);
-				}
-			}
-			for v in neg_local {
-				negative_ids.extend(v);
+					(Some(a), None) => a,
+					(None, Some(b)) => b,
+					(None, None) => return Err(error!("No results for author: {name}")),
+				};
+				union.sort_unstable();
+				union.dedup();
+				positive_results.push(union);
 			}
-			if !neg_reqs.is_empty() {
-				let mut reqs: Vec<Request> = Vec::new();
-				for url in &neg_reqs {
-					reqs.push(Request::get(url)?.header("Referer", REFERER));
+
+			let mut negative_ids: Vec<i64> = Vec::new();
+			// Parallelize negative_terms requests: local plain-text first, batch nozomi for ns:tags
+			if !negative_terms.is_empty() {
+				let mut neg_local: Vec<Vec<i64>> = Vec::new();
+				let mut neg_reqs: Vec<String> = Vec::new();
+				for term in &negative_terms {
+					if term.contains(':') {
+						if let Some(url) = nozomi_url_for_ns_tag(term, &lang) {
+							neg_reqs.push(url);
+						}
+					} else if let Some(ids) = search_plain_text(term, &index_version) {
+						neg_local.push(ids);
+					}
 				}
-				let responses = Request::send_all(reqs);
-				for r in responses.into_iter().flatten() {
-					if let Ok(data) = r.get_data() {
-						negative_ids.extend(decode_nozomi(&data));
+				for v in neg_local {
+					negative_ids.extend(v);
+				}
+				if !neg_reqs.is_empty() {
+					let mut reqs: Vec<Request> = Vec::new();
+					for url in &neg_reqs {
+						reqs.push(Request::get(url)?.header("Referer", REFERER));
+					}
+					let responses = Request::send_all(reqs);
+					for r in responses.into_iter().flatten() {
+						if let Ok(data) = r.get_data() {
+							negative_ids.extend(decode_nozomi(&data));
+						}
 					}
 				}
 			}
-		}
-		negative_ids.sort_unstable();
-		negative_ids.dedup();
-
-		let mut result_ids: Vec<i64> = if use_sort_base {
-			let data = Request::get(&sort_nozomi_url)?
-				.header("Referer", REFERER)
-				.data()?;
-			decode_nozomi(&data)
-		} else {
-			Vec::new()
-		};
-
-		for pos_ids in positive_results {
-			if result_ids.is_empty() {
-				result_ids = pos_ids;
+			negative_ids.sort_unstable();
+			negative_ids.dedup();
+
+			let mut result_ids: Vec<i64> = if use_sort_base {
+				let data = Request::get(&sort_nozomi_url)?
+					.header("Referer", REFERER)
+					.data()?;
+				decode_nozomi(&data)
 			} else {
-				let pos_set: Vec<i64> = {
-					let mut v = pos_ids;
-					v.sort_unstable();
-					v
-				};
-				result_ids.retain(|id| pos_set.binary_search(id).is_ok());
+				Vec::new()
+			};
+
+			for pos_ids in positive_results {
+				if result_ids.is_empty() {
+					result_ids = pos_ids;
+				} else {
+					let pos_set: Vec<i64> = {
+						let mut v = pos_ids;
+						v.sort_unstable();
+						v
+					};
+					result_ids.retain(|id| pos_set.binary_search(id).is_ok());
+				}
+			}
+
+			if let Some(ref t) = type_filter {
+				let type_url = format!("{LTN_URL}/type/{t}-{lang}.nozomi");
+				if let Ok(data) =
+					Request::get(&type_url).and_then(|r| r.header("Referer", REFERER).data())
+				{
+					let mut type_ids = decode_nozomi(&data);
+					type_ids.sort_unstable();
+					result_ids.retain(|id| type_ids.binary_search(id).is_ok());
+				}
 			}
-		}
 
-		if let Some(ref t) = type_filter {
-			let type_url = format!("{LTN_URL}/type/{t}-{lang}.nozomi");
-			if let Ok(data) =
-				Request::get(&type_url).and_then(|r| r.header("Referer", REFERER).data())
-			{
-				let mut type_ids = decode_nozomi(&data);
-				type_ids.sort_unstable();
-				result_ids.retain(|id| type_ids.binary_search(id).is_ok());
+			if !negative_ids.is_empty() {
+				result_ids.retain(|id| negative_ids.binary_search(id).is_err());
 			}
-		}
 
-		if !negative_ids.is_empty() {
-			result_ids.retain(|id| negative_ids.binary_search(id).is_err());
+			Ok(result_ids)
+		};
+
+		let is_cached = self.search_cache.borrow().as_ref().is_some_and(|cache| {
+			cache.key == cache_key && cache.version == index_version && !index_version.is_empty()
+		});
+		if !is_cached {
+			let ids = compute_result_ids()?;
+			*self.search_cache.borrow_mut() = Some(SearchCache {
+				key: cache_key,
+				version: index_version,
+				ids,
+			});
 		}
+		let cache = self.search_cache.borrow();
+		let result_ids = cache.as_ref().map(|c| c.ids.as_slice()).unwrap_or_default();
 
 		let start = ((page - 1) * PAGE_SIZE) as usize;
 		let end = (start + PAGE_SIZE as usize).min(result_ids.len());
@@ -57,7 +57,14 @@ fn hash_term(term: &str) -> [u8; 4] {
 	[result[0], result[1], result[2], result[3]]
 }
 
-fn fetch_galleries_index_version() -> Option<String> {
+/// Result gallery ids of a search query, valid for a single galleriesindex version.
+pub struct SearchCache {
+	pub key: String,
+	pub version: String,
+	pub ids: Vec<i64>,
+}
+
+pub fn fetch_galleries_index_version() -> Option<String> {
 	let url = format!("{LTN_URL}/galleriesindex/version?_=0");
 	Request::get(&url)
 		.ok()?
@@ -231,11 +238,13 @@ fn fetch_galleryids_from_data(version: &str, offset: u64, length: u32) -> Option
 	Some(ids)
 }
 
-pub fn search_plain_text(term: &str) -> Option<Vec<i64>> {
+pub fn search_plain_text(term: &str, version: &str) -> Option<Vec<i64>> {
+	if version.is_empty() {
+		return None;
+	}
 	let normalized = term.replace('_', " ").to_lowercase();
 	let key = hash_term(&normalized);
-	let version = fetch_galleries_index_version()?;
-	let root = fetch_node(&version, 0)?;
-	let (offset, length) = b_search(&key, &root, &version)?;
-	fetch_galleryids_from_data(&version, offset, length)
+	let root = fetch_node(version, 0)?;
+	let (offset, length) = b_search(&key, &root, version)?;
+	fetch_galleryids_from_data(version, offset, length)
 }
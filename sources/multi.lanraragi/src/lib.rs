@@ -2,20 +2,98 @@
 
 use crate::models::{Archive, ArchiveMetadata, Category, SearchResult};
 use aidoku::{
-	BaseUrlProvider, Chapter, DynamicFilters, Filter, FilterValue, Manga, MangaPageResult,
-	MangaStatus, Page, PageContent, Result, SelectFilter, Source,
+	BaseUrlProvider, Chapter, DynamicFilters, Filter, FilterValue, ImageRef, ImageRequestProvider,
+	ImageResponse, Listing, ListingProvider, Manga, MangaPageResult, MangaStatus, Page,
+	PageContent, PageContext, PageImageProcessor, Result, SelectFilter, Source,
 	alloc::{String, Vec, string::ToString, vec},
 	helpers::uri::QueryParameters,
-	imports::net::Request,
+	imports::net::{HttpMethod, Request},
 	prelude::*,
 };
 use core::cell::RefCell;
 mod models;
 mod settings;
 
+const RANDOM_COUNT: i32 = 20;
+
 #[derive(Default)]
 struct Lanraragi {
 	current_start: RefCell<i32>,
+	// the archive and page of the last reading progress sent to the server
+	last_progress: RefCell<Option<(String, i32)>>,
+}
+
+// creates a request with the api key authorization header, if configured
+fn api_request(url: &str, method: HttpMethod) -> Result<Request> {
+	let mut request = Request::new(url, method)?;
+	let api_key = settings::get_api_key();
+	if !api_key.is_empty() {
+		let encoded_key = base64::Engine::encode(
+			&base64::engine::general_purpose::STANDARD,
+			api_key.as_bytes(),
+		);
+		request = request.header("Authorization", &format!("Bearer {}", encoded_key));
+	}
+	Ok(request)
+}
+
+impl Lanraragi {
+	fn search(&self, qs: QueryParameters, page: i32) -> Result<MangaPageResult> {
+		self.search_filtered(qs, page, |_| true)
+	}
+
+	// searches the archive index, keeping only the archives matching `predicate`
+	fn search_filtered(
+		&self,
+		mut qs: QueryParameters,
+		page: i32,
+		predicate: impl Fn(&Archive) -> bool,
+	) -> Result<MangaPageResult> {
+		let base_url = settings::get_base_url()?;
+
+		if page == 1 {
+			*self.current_start.borrow_mut() = 0;
+		}
+		let mut start = *self.current_start.borrow();
+		if page > 1 {
+			qs.set("start", Some(&start.to_string()));
+		}
+
+		let mut entries = Vec::new();
+		let mut page_size = None;
+		let has_next_page = loop {
+			let url = format!("{}/api/search?{}", base_url, qs);
+
+			let request = api_request(&url, HttpMethod::Get)?;
+			let search_result: SearchResult = request.send()?.get_json()?;
+
+			let current_result_count = search_result.data.len() as i32;
+			start += current_result_count;
+			*self.current_start.borrow_mut() = start;
+
+			// the first response sets how many entries should fill the page
+			let target = *page_size.get_or_insert(search_result.data.len());
+			entries.extend(
+				search_result
+					.data
+					.into_iter()
+					.filter(|archive| predicate(archive))
+					.map(|archive| archive.into_manga(&base_url)),
+			);
+
+			let has_next_page = start < search_result.records_filtered;
+			// keep fetching when the predicate skipped archives, so pages aren't left empty
+			if !has_next_page || current_result_count == 0 || entries.len() >= target {
+				break has_next_page && current_result_count > 0;
+			}
+			qs.set("start", Some(&start.to_string()));
+		};
+
+		Ok(MangaPageResult {
+			entries,
+			has_next_page,
+		})
+	}
 }
 
 impl Source for Lanraragi {
@@ -29,9 +107,6 @@ impl Source for Lanraragi {
 		page: i32,
 		filters: Vec<FilterValue>,
 	) -> Result<MangaPageResult> {
-		let base_url = settings::get_base_url()?;
-		let api_key = settings::get_api_key();
-
 		let mut qs = QueryParameters::new();
 		qs.push("sortby", Some("date_added"));
 		qs.push("order", Some("desc"));
@@ -75,37 +150,7 @@ impl Source for Lanraragi {
 			qs.set("filter", Some(&combined_query));
 		}
 
-		let start = *self.current_start.borrow();
-		if page > 1 {
-			qs.set("start", Some(&start.to_string()));
-		}
-
-		let url = format!("{}/api/search?{}", base_url, qs);
-
-		let mut request = Request::get(&url)?;
-		if !api_key.is_empty() {
-			let encoded_key = base64::Engine::encode(
-				&base64::engine::general_purpose::STANDARD,
-				api_key.as_bytes(),
-			);
-			request = request.header("Authorization", &format!("Bearer {}", encoded_key));
-		}
-		let search_result: SearchResult = request.send()?.get_json()?;
-
-		let archives_len = search_result.data.len();
-
-		let current_result_count = archives_len as i32;
-
-		*self.current_start.borrow_mut() += current_result_count;
-
-		Ok(MangaPageResult {
-			entries: search_result
-				.data
-				.into_iter()
-				.map(|archive| archive.into_manga(&base_url))
-				.collect(),
-			has_next_page: start + current_result_count < search_result.records_filtered,
-		})
+		self.search(qs, page)
 	}
 
 	fn get_manga_update(
@@ -115,19 +160,21 @@ impl Source for Lanraragi {
 		needs_chapters: bool,
 	) -> Result<Manga> {
 		let base_url = settings::get_base_url()?;
-		let api_key = settings::get_api_key();
 		let url = format!("{}/api/archives/{}/metadata", base_url, manga.key);
-		let mut request = Request::get(&url)?;
-		if !api_key.is_empty() {
-			let encoded_key = base64::Engine::encode(
-				&base64::engine::general_purpose::STANDARD,
-				api_key.as_bytes(),
-			);
-			request = request.header("Authorization", &format!("Bearer {}", encoded_key));
-		}
+		let request = api_request(&url, HttpMethod::Get)?;
 		let archive: Archive = request.send()?.get_json()?;
 
 		if needs_details {
+			// opening a title clears its "new" flag on the server, which is done here rather
+			// than when fetching pages so that downloads don't clear it
+			if archive.isnew == "true" {
+				let _ = api_request(
+					&format!("{}/api/archives/{}/isnew", base_url, archive.arcid),
+					HttpMethod::Delete,
+				)
+				.and_then(|req| Ok(req.send()?));
+			}
+
 			manga.title = archive.title;
 
 			// Extract first rating: tag value
@@ -205,9 +252,20 @@ impl Source for Lanraragi {
 				}
 			});
 
+			// reflect the server side reading progress in the chapter title
+			let title = match archive.progress {
+				Some(progress) if progress >= archive.pagecount => {
+					format!("{} pages (read)", archive.pagecount)
+				}
+				Some(progress) if progress > 0 => {
+					format!("{} pages (page {})", archive.pagecount, progress)
+				}
+				_ => format!("{} pages", archive.pagecount),
+			};
+
 			let chapter = Chapter {
 				key: archive.arcid.clone(),
-				title: Some(format!("{} pages", archive.pagecount)),
+				title: Some(title),
 				chapter_number: Some(1.0),
 				date_uploaded,
 				url: Some(format!("{}/reader?id={}", base_url, archive.arcid)),
@@ -221,27 +279,22 @@ impl Source for Lanraragi {
 
 	fn get_page_list(&self, _manga: Manga, chapter: Chapter) -> Result<Vec<Page>> {
 		let base_url = settings::get_base_url()?;
-		let api_key = settings::get_api_key();
 		let url = format!("{}/api/archives/{}/files", base_url, chapter.key);
 
-		let mut request = Request::get(&url)?;
-		if !api_key.is_empty() {
-			let encoded_key = base64::Engine::encode(
-				&base64::engine::general_purpose::STANDARD,
-				api_key.as_bytes(),
-			);
-			request = request.header("Authorization", &format!("Bearer {}", encoded_key));
-		}
+		let request = api_request(&url, HttpMethod::Get)?;
 		let archive_metadata: ArchiveMetadata = request.send()?.get_json()?;
 
-		let base_url = settings::get_base_url()?;
 		Ok(archive_metadata
 			.pages
 			.into_iter()
-			.map(|page_url| {
-				let full_url = format!("{}{}", base_url, page_url);
+			.enumerate()
+			.map(|(index, page_url)| {
+				// used to send the reading progress once the page is shown
+				let mut context = PageContext::new();
+				context.insert("arcid".into(), chapter.key.clone());
+				context.insert("page".into(), (index + 1).to_string());
 				Page {
-					content: PageContent::Url(full_url, None),
+					content: PageContent::url_context(format!("{}{}", base_url, page_url), context),
 					..Default::default()
 				}
 			})
@@ -249,6 +302,51 @@ impl Source for Lanraragi {
 	}
 }
 
+impl ListingProvider for Lanraragi {
+	fn get_manga_list(&self, listing: Listing, page: i32) -> Result<MangaPageResult> {
+		let mut qs = QueryParameters::new();
+		match listing.id.as_str() {
+			"New Archives" => {
+				qs.push("newonly", Some("true"));
+				qs.push("sortby", Some("date_added"));
+				qs.push("order", Some("desc"));
+			}
+			"In Progress" => {
+				qs.push("sortby", Some("lastread"));
+				qs.push("order", Some("desc"));
+				// the server includes archives that were never opened or are finished, so skip them
+				return self.search_filtered(qs, page, |archive| {
+					archive
+						.progress
+						.is_some_and(|progress| progress > 0 && progress < archive.pagecount)
+				});
+			}
+			"Random" => {
+				let base_url = settings::get_base_url()?;
+				let url = format!("{}/api/search/random?count={}", base_url, RANDOM_COUNT);
+				let result: SearchResult =
+					api_request(&url, HttpMethod::Get)?.send()?.get_json()?;
+				return Ok(MangaPageResult {
+					entries: result
+						.data
+						.into_iter()
+						.map(|archive| archive.into_manga(&base_url))
+						.collect(),
+					has_next_page: false,
+				});
+			}
+			_ => bail!("Invalid listing"),
+		}
+		self.search(qs, page)
+	}
+}
+
+impl ImageRequestProvider for Lanraragi {
+	fn get_image_request(&self, url: String, _context: Option<PageContext>) -> Result<Request> {
+		api_request(&url, HttpMethod::Get)
+	}
+}
+
 impl BaseUrlProvider for Lanraragi {
 	fn get_base_url(&self) -> Result<String> {
 		settings::get_base_url()
@@ -258,17 +356,9 @@ impl BaseUrlProvider for Lanraragi {
 impl DynamicFilters for Lanraragi {
 	fn get_dynamic_filters(&self) -> Result<Vec<Filter>> {
 		let base_url = settings::get_base_url()?;
-		let api_key = settings::get_api_key();
 		let url = format!("{}/api/categories", base_url);
 
-		let mut request = Request::get(&url)?;
-		if !api_key.is_empty() {
-			let encoded_key = base64::Engine::encode(
-				&base64::engine::general_purpose::STANDARD,
-				api_key.as_bytes(),
-			);
-			request = request.header("Authorization", &format!("Bearer {}", encoded_key));
-		}
+		let request = api_request(&url, HttpMethod::Get)?;
 		let categories: Vec<Category> = request.send()?.get_json()?;
 
 		let mut options = vec!["All".to_string()];
@@ -308,4 +398,53 @@ impl DynamicFilters for Lanraragi {
 	}
 }
 
-register_source!(Lanraragi, BaseUrlProvider, DynamicFilters);
+impl PageImageProcessor for Lanraragi {
+	fn process_page_image(
+		&self,
+		response: ImageResponse,
+		context: Option<PageContext>,
+	) -> Result<ImageRef> {
+		let Some(context) = context else {
+			return Ok(response.image);
+		};
+		let (Some(arcid), Some(page)) = (
+			context.get("arcid"),
+			context.get("page").and_then(|s| s.parse::<i32>().ok()),
+		) else {
+			return Ok(response.image);
+		};
+
+		// only moves the progress forward, since pages are also loaded ahead of the current one
+		// and when going back
+		let mut last_progress = self.last_progress.borrow_mut();
+		let is_ahead = match last_progress.as_ref() {
+			Some((last_arcid, last_page)) => last_arcid != arcid || *last_page < page,
+			None => true,
+		};
+		if is_ahead {
+			// a failed update is retried with the next page instead of failing the image
+			let sent = settings::get_base_url()
+				.and_then(|base_url| {
+					api_request(
+						&format!("{}/api/archives/{}/progress/{}", base_url, arcid, page),
+						HttpMethod::Put,
+					)
+				})
+				.and_then(|req| Ok(req.send()?));
+			if sent.is_ok() {
+				*last_progress = Some((arcid.clone(), page));
+			}
+		}
+
+		Ok(response.image)
+	}
+}
+
+register_source!(
+	Lanraragi,
+	ListingProvider,
+	ImageRequestProvider,
+	BaseUrlProvider,
+	DynamicFilters,
+	PageImageProcessor
+);
@@ -0,0 +1,89 @@
+use crate::{API_URL, models::DexAtHomeResponse, settings};
+use aidoku::{
+	Result,
+	alloc::{String, Vec, string::ToString},
+	imports::{error::AidokuError, net::Request},
+	prelude::*,
+};
+use serde_json::json;
+
+const REPORT_URL: &str = "https://api.mangadex.network/report";
+
+#[link(wasm_import_module = "std")]
+unsafe extern "C" {
+	// the host clock in fractional seconds, which `current_date` truncates to whole seconds
+	#[link_name = "current_date"]
+	fn host_current_date() -> f64;
+}
+
+// the current time in milliseconds, for timing image loads
+pub fn now_ms() -> i64 {
+	(unsafe { host_current_date() } * 1000.0) as i64
+}
+
+// fetch an at-home server for a chapter, returning the image base url and page file names
+pub fn get_chapter_server(chapter_id: &str) -> Result<(String, Vec<String>)> {
+	let force_port = settings::get_force_port();
+	let url = format!(
+		"{API_URL}/at-home/server/{chapter_id}{}",
+		if force_port { "?forcePort443=true" } else { "" }
+	);
+
+	let mut response = Request::get(&url)?.send()?;
+	let response = response.get_json::<DexAtHomeResponse>()?;
+
+	let data_saver = settings::get_data_saver();
+	let base_url = format!(
+		"{}/{}/{}",
+		response.base_url,
+		if data_saver { "data-saver" } else { "data" },
+		response.chapter.hash
+	);
+
+	let chapter_data = if data_saver {
+		response.chapter.data_saver
+	} else {
+		response.chapter.data
+	};
+	let files = chapter_data
+		.ok_or(AidokuError::message("Missing chapter data"))?
+		.into_iter()
+		.map(String::from)
+		.collect();
+
+	Ok((base_url, files))
+}
+
+// images served by mangadex.org itself aren't part of the at-home network
+fn is_at_home_url(url: &str) -> bool {
+	let host = url
+		.split_once("://")
+		.map(|(_, rest)| rest)
+		.unwrap_or(url)
+		.split(['/', ':'])
+		.next()
+		.unwrap_or_default();
+	!host.is_empty() && host != "mangadex.org" && !host.ends_with(".mangadex.org")
+}
+
+// report the result of an image load to the at-home network (https://api.mangadex.org/docs/04-chapter/retrieving-chapter/)
+// `duration` is the time the request took in milliseconds
+pub fn report(url: &str, success: bool, cached: bool, bytes: usize, duration: i64) {
+	if !is_at_home_url(url) {
+		return;
+	}
+	let body = json!({
+		"url": url,
+		"success": success,
+		"cached": cached,
+		"bytes": bytes,
+		"duration": duration,
+	});
+	if let Ok(request) = Request::post(REPORT_URL) {
+		// reporting is best-effort, so failures are ignored
+		let _ = request
+			.header("Content-Type", "application/json")
+			.body(body.to_string())
+			.send();
+	}
+}
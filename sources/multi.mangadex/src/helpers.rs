@@ -0,0 +1,173 @@
+use crate::models::DexChapter;
+use aidoku::{
+	Chapter,
+	alloc::{String, Vec},
+	prelude::format,
+};
+use hashbrown::HashMap;
+
+// a chapter along with the values used to pick between duplicate uploads
+pub struct RankedChapter {
+	pub chapter: Chapter,
+	// index in the preferred groups list, lower is better
+	priority: Option<usize>,
+	official: bool,
+}
+
+impl RankedChapter {
+	pub fn new(value: DexChapter<'_>, preferred_groups: &[String]) -> Self {
+		let priority = value
+			.group_ids()
+			.iter()
+			.filter_map(|id| preferred_groups.iter().position(|group| group == id))
+			.min();
+		let official = value.is_official();
+		Self {
+			chapter: value.into(),
+			priority,
+			official,
+		}
+	}
+
+	// chapters without a number are never merged, since they're usually different oneshots
+	// the volume isn't part of the key, since some uploads of a chapter leave it out
+	fn dedup_key(&self) -> String {
+		let chapter = &self.chapter;
+		match chapter.chapter_number {
+			Some(number) => format!(
+				"{}|{number}",
+				chapter.language.as_deref().unwrap_or_default(),
+			),
+			None => chapter.key.clone(),
+		}
+	}
+}
+
+fn is_better(new: &RankedChapter, current: &RankedChapter) -> bool {
+	match (new.priority, current.priority) {
+		(Some(new_priority), Some(cur_priority)) if new_priority != cur_priority => {
+			return new_priority < cur_priority;
+		}
+		(Some(_), None) => return true,
+		(None, Some(_)) => return false,
+		_ => {}
+	}
+
+	// keeps the chapter grouped under its volume
+	match (new.chapter.volume_number, current.chapter.volume_number) {
+		(Some(_), None) => return true,
+		(None, Some(_)) => return false,
+		_ => {}
+	}
+
+	if new.official && !current.official {
+		return true;
+	}
+	if !new.official && current.official {
+		return false;
+	}
+
+	new.chapter.date_uploaded > current.chapter.date_uploaded
+}
+
+// keeps the best upload for each (language, chapter) while preserving the feed order
+pub fn dedup_insert(
+	chapters: &mut Vec<RankedChapter>,
+	positions: &mut HashMap<String, usize>,
+	chapter: RankedChapter,
+) {
+	let key = chapter.dedup_key();
+	match positions.get(&key) {
+		None => {
+			positions.insert(key, chapters.len());
+			chapters.push(chapter);
+		}
+		Some(&idx) => {
+			if is_better(&chapter, &chapters[idx]) {
+				chapters[idx] = chapter;
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use aidoku::alloc::vec;
+	use aidoku_test::aidoku_test;
+
+	fn ranked(
+		key: &str,
+		volume: Option<f32>,
+		chapter: Option<f32>,
+		priority: Option<usize>,
+		official: bool,
+	) -> RankedChapter {
+		RankedChapter {
+			chapter: Chapter {
+				key: key.into(),
+				volume_number: volume,
+				chapter_number: chapter,
+				language: Some("en".into()),
+				..Default::default()
+			},
+			priority,
+			official,
+		}
+	}
+
+	fn dedup(values: Vec<RankedChapter>) -> Vec<String> {
+		let mut chapters = Vec::new();
+		let mut positions = HashMap::new();
+		for value in values {
+			dedup_insert(&mut chapters, &mut positions, value);
+		}
+		chapters.into_iter().map(|c| c.chapter.key).collect()
+	}
+
+	#[aidoku_test]
+	fn test_dedup_key() {
+		assert_eq!(
+			ranked("a", Some(1.0), Some(5.0), None, false).dedup_key(),
+			ranked("b", None, Some(5.0), None, false).dedup_key()
+		);
+		assert_ne!(
+			ranked("a", None, Some(5.0), None, false).dedup_key(),
+			ranked("b", None, Some(5.5), None, false).dedup_key()
+		);
+		assert_ne!(
+			ranked("a", None, None, None, false).dedup_key(),
+			ranked("b", None, None, None, false).dedup_key()
+		);
+	}
+
+	#[aidoku_test]
+	fn test_dedup_prefers_volume() {
+		assert_eq!(
+			dedup(vec![
+				ranked("a", None, Some(1.0), None, false),
+				ranked("b", Some(1.0), Some(1.0), None, false),
+				ranked("c", None, Some(2.0), None, false),
+			]),
+			vec![String::from("b"), String::from("c")]
+		);
+		assert_eq!(
+			dedup(vec![
+				ranked("a", Some(1.0), Some(1.0), None, false),
+				ranked("b", None, Some(1.0), None, true),
+			]),
+			vec![String::from("a")]
+		);
+	}
+
+	#[aidoku_test]
+	fn test_dedup_prefers_group_priority() {
+		assert_eq!(
+			dedup(vec![
+				ranked("a", Some(1.0), Some(1.0), Some(1), false),
+				ranked("b", None, Some(1.0), Some(0), false),
+			]),
+			vec![String::from("b")]
+		);
+	}
+}
@@ -109,6 +109,8 @@ impl Home for MangaDex {
 			custom_lists.extend(current_seasonal_lists);
 		};
 
+		let logged_in = settings::is_logged_in();
+
 		// send basic home layout
 		{
 			let mut components = vec![
@@ -123,6 +125,13 @@ impl Home for MangaDex {
 					value: aidoku::HomeComponentValue::empty_manga_chapter_list(),
 				},
 			];
+			if logged_in {
+				components.push(HomeComponent {
+					title: Some("Followed Updates".into()),
+					subtitle: None,
+					value: aidoku::HomeComponentValue::empty_manga_chapter_list(),
+				});
+			}
 			for CustomList { name, .. } in custom_lists.iter() {
 				components.push(HomeComponent {
 					title: Some(name.clone()),
@@ -295,6 +304,29 @@ impl Home for MangaDex {
 			}));
 		}
 
+		// followed updates list
+		if logged_in {
+			let mut entries = self
+				.get_followed_feed(1)
+				.map(|(entries, _)| entries)
+				.unwrap_or_default();
+			entries.truncate(6);
+
+			send_partial_result(&HomePartialResult::Component(HomeComponent {
+				title: Some(String::from("Followed Updates")),
+				subtitle: None,
+				value: aidoku::HomeComponentValue::MangaChapterList {
+					page_size: None,
+					entries,
+					listing: Some(Listing {
+						id: String::from("follows"),
+						name: String::from("Followed Updates"),
+						kind: ListingKind::Default,
+					}),
+				},
+			}));
+		}
+
 		// custom lists components
 		{
 			let custom_list_responses = Request::send_all(custom_lists.iter().map(|list| {
@@ -1,27 +1,34 @@
 #![no_std]
 use aidoku::{
-	AlternateCoverProvider, Chapter, DeepLinkHandler, DeepLinkResult, DynamicListings, FilterValue,
-	ImageRequestProvider, Listing, ListingKind, ListingProvider, Manga, MangaPageResult, Page,
-	PageContent, Result, Source,
+	AlternateCoverProvider, ButtonSetting, Chapter, DeepLinkHandler, DeepLinkResult,
+	DynamicListings, DynamicSettings, FilterValue, GroupSetting, ImageRequestProvider,
+	ImageResponse, Listing, ListingKind, ListingProvider, Manga, MangaPageResult, MangaWithChapter,
+	NotificationHandler, Page, PageContent, PageContext, PageImageProcessor, Result, SelectSetting,
+	Setting, Source,
 	alloc::{String, Vec, string::ToString, vec},
 	helpers::uri::QueryParameters,
 	imports::{
+		canvas::ImageRef,
 		error::AidokuError,
 		net::{Request, TimeUnit, set_rate_limit},
 		std::send_partial_result,
 	},
 	prelude::*,
 };
-use core::fmt::Write;
-use hashbrown::HashSet;
+use core::{cell::RefCell, fmt::Write};
+use hashbrown::{HashMap, HashSet};
+use serde_json::{Value, json};
 
+mod athome;
 mod auth;
+mod helpers;
 mod models;
 mod settings;
 
 mod home;
 
 use auth::*;
+use helpers::RankedChapter;
 use models::*;
 
 const API_URL: &str = "https://api.mangadex.org";
@@ -29,7 +36,19 @@ const COVER_URL: &str = "https://uploads.mangadex.org";
 const REFERER: &str = "https://mangadex.org/";
 
 const PAGE_SIZE: i32 = 20;
+const FEED_PAGE_SIZE: i32 = 100;
 const CUSTOM_LIST_PREFIX: &str = "list-";
+const LIBRARY_PREFIX: &str = "library-";
+
+// reading statuses, with their listing names
+const LIBRARY_STATUSES: &[(&str, &str)] = &[
+	("reading", "Reading"),
+	("on_hold", "On Hold"),
+	("plan_to_read", "Plan to Read"),
+	("dropped", "Dropped"),
+	("re_reading", "Re-Reading"),
+	("completed", "Completed"),
+];
 
 // listings to use on the home page
 const CUSTOM_LISTS: &[&str] = &[
@@ -38,13 +57,19 @@ const CUSTOM_LISTS: &[&str] = &[
 	"5c5e6e39-0b4b-413e-be59-27b1ba03d1b9", // Featured by Supporters
 ];
 
-struct MangaDex;
+#[derive(Default)]
+struct MangaDex {
+	// replacement at-home servers for chapters whose original server failed
+	image_servers: RefCell<HashMap<String, String>>,
+	// the chapter and start time in milliseconds of each pending image request, by url
+	request_times: RefCell<HashMap<String, (String, i64)>>,
+}
 
 impl Source for MangaDex {
 	fn new() -> Self {
 		// 5 requests per second (https://api.mangadex.org/docs/2-limitations/)
 		set_rate_limit(5, 1, TimeUnit::Seconds);
-		Self
+		Self::default()
 	}
 
 	fn get_search_manga_list(
@@ -211,6 +236,10 @@ impl Source for MangaDex {
 				.data
 				.into(),
 			);
+			// remembered for changing the reading status in settings
+			if settings::is_logged_in() {
+				settings::set_last_title(&manga.key, &manga.title);
+			}
 			if needs_chapters {
 				send_partial_result(&manga);
 			}
@@ -239,19 +268,28 @@ impl Source for MangaDex {
 				if show_unavailable_chapters { "1" } else { "0" }
 			);
 
-			let (mut chapters, total) = Request::get(&url)?
+			let deduplicate = settings::deduped_chapter();
+			let preferred_groups = settings::get_preferred_groups();
+			let mut chapters: Vec<RankedChapter> = Vec::new();
+			let mut positions = HashMap::new();
+
+			let mut add_chapters = |data: Vec<DexChapter>| {
+				for value in data.into_iter().filter(|value| !value.has_external_url()) {
+					let chapter = RankedChapter::new(value, &preferred_groups);
+					if deduplicate {
+						helpers::dedup_insert(&mut chapters, &mut positions, chapter);
+					} else {
+						chapters.push(chapter);
+					}
+				}
+			};
+
+			let total = Request::get(&url)?
 				.send()?
 				.get_json::<DexResponse<Vec<DexChapter>>>()
 				.map(|response| {
-					(
-						response
-							.data
-							.into_iter()
-							.filter(|value| !value.has_external_url())
-							.map(|value| value.into())
-							.collect::<Vec<Chapter>>(),
-						response.total,
-					)
+					add_chapters(response.data);
+					response.total
 				})?;
 
 			// fetch chapters in pages of 500
@@ -263,60 +301,40 @@ impl Source for MangaDex {
 						.send()?
 						.get_json::<DexResponse<Vec<DexChapter>>>()
 					{
-						chapters.extend(
-							response
-								.data
-								.into_iter()
-								.filter(|value| !value.has_external_url())
-								.map(|value| value.into()),
-						);
+						add_chapters(response.data);
 					}
 					offset += 500;
 				}
 			}
 
-			manga.chapters = Some(chapters);
+			manga.chapters = Some(chapters.into_iter().map(|value| value.chapter).collect());
 		}
 
 		Ok(manga)
 	}
 
 	fn get_page_list(&self, _manga: Manga, chapter: Chapter) -> Result<Vec<Page>> {
-		let force_port = settings::get_force_port();
-		let url = format!(
-			"{API_URL}/at-home/server/{}{}",
-			chapter.key,
-			if force_port { "?forcePort443=true" } else { "" }
-		);
+		let (base_url, files) = athome::get_chapter_server(&chapter.key)?;
 
-		Request::get(&url)?
-			.send()?
-			.get_json::<DexAtHomeResponse>()
-			.and_then(|response| {
-				let data_saver = settings::get_data_saver();
-				let base_url = format!(
-					"{}/{}/{}",
-					response.base_url,
-					if data_saver { "data-saver" } else { "data" },
-					response.chapter.hash
-				);
-
-				let chapter_data = if data_saver {
-					response.chapter.data_saver
-				} else {
-					response.chapter.data
-				};
-				chapter_data
-					.map(|data| {
-						data.iter()
-							.map(|value| Page {
-								content: PageContent::url(format!("{base_url}/{value}")),
-								..Default::default()
-							})
-							.collect::<Vec<Page>>()
-					})
-					.ok_or(AidokuError::message("Missing chapter data"))
+		// a new server is requested whenever the chapter is opened again
+		self.image_servers.borrow_mut().remove(&chapter.key);
+		// requests from a previous opening that never completed aren't timed anymore
+		self.request_times
+			.borrow_mut()
+			.retain(|_, (chapter_id, _)| *chapter_id != chapter.key);
+
+		Ok(files
+			.into_iter()
+			.map(|file| {
+				let mut context = PageContext::new();
+				context.insert("chapter".into(), chapter.key.clone());
+				context.insert("file".into(), file.clone());
+				Page {
+					content: PageContent::url_context(format!("{base_url}/{file}"), context),
+					..Default::default()
+				}
 			})
+			.collect())
 	}
 }
 
@@ -342,7 +360,17 @@ impl ListingProvider for MangaDex {
 				}],
 			),
 			"latest" => self.get_latest_manga(page),
-			"library" => self.get_library(page),
+			"library" => self.get_library(page, None),
+			"follows" => {
+				let (entries, has_next_page) = self.get_followed_feed(page)?;
+				Ok(MangaPageResult {
+					entries: entries.into_iter().map(|entry| entry.manga).collect(),
+					has_next_page,
+				})
+			}
+			_ if listing.id.starts_with(LIBRARY_PREFIX) => {
+				self.get_library(page, Some(&listing.id[LIBRARY_PREFIX.len()..]))
+			}
 			_ if listing.id.starts_with(CUSTOM_LIST_PREFIX) => {
 				self.get_mangadex_list(&listing.id[CUSTOM_LIST_PREFIX.len()..])
 			}
@@ -467,9 +495,13 @@ impl MangaDex {
 		})
 	}
 
-	// get the logged in user's library
-	fn get_library(&self, page: i32) -> Result<MangaPageResult> {
-		let status_ids = Request::get(format!("{API_URL}/manga/status"))?
+	// get the logged in user's library, optionally only titles with the given reading status
+	fn get_library(&self, page: i32, status: Option<&str>) -> Result<MangaPageResult> {
+		let url = match status {
+			Some(status) => format!("{API_URL}/manga/status?status={status}"),
+			None => format!("{API_URL}/manga/status"),
+		};
+		let status_ids = Request::get(url)?
 			.authed_send()?
 			.get_json::<DexStatusResponse>()?
 			.statuses
@@ -479,6 +511,11 @@ impl MangaDex {
 				output
 			});
 
+		// the manga endpoint would return everything without any ids
+		if status_ids.is_empty() {
+			return Ok(MangaPageResult::default());
+		}
+
 		let offset = (page - 1) * PAGE_SIZE;
 
 		let manga_url = format!(
@@ -512,20 +549,213 @@ impl MangaDex {
 			has_next_page,
 		})
 	}
+
+	// get the latest chapters of followed titles, with one entry per title
+	pub fn get_followed_feed(&self, page: i32) -> Result<(Vec<MangaWithChapter>, bool)> {
+		let languages = settings::get_languages_with_key("translatedLanguage")?;
+		let content_ratings = settings::get_content_ratings()?;
+
+		let offset = (page - 1) * FEED_PAGE_SIZE;
+
+		let mut feed_res = Request::get(format!(
+			"{API_URL}/user/follows/manga/feed\
+				?includes[]=scanlation_group\
+				&includes[]=user\
+				&limit={FEED_PAGE_SIZE}\
+				&offset={offset}\
+				&order[readableAt]=desc\
+				{content_ratings}\
+				{languages}"
+		))?
+		.authed_send()?;
+		let feed = feed_res.get_json::<DexResponse<Vec<DexChapter>>>()?;
+		let has_next_page = feed.total.is_some_and(|t| offset + FEED_PAGE_SIZE < t);
+
+		// the feed is sorted by newest first, so keep the first chapter of each title
+		let mut seen = HashSet::new();
+		let chapters: Vec<DexChapter> = feed
+			.data
+			.into_iter()
+			.filter(|chapter| !chapter.has_external_url())
+			.filter(|chapter| chapter.manga_id().is_some_and(|id| seen.insert(id)))
+			.collect();
+
+		if chapters.is_empty() {
+			return Ok((Vec::new(), has_next_page));
+		}
+
+		let manga_ids = chapters
+			.iter()
+			.filter_map(|value| value.manga_id().map(|id| format!("&ids[]={id}")))
+			.collect::<String>();
+		let manga = Request::get(format!(
+			"{API_URL}/manga\
+				?limit=100\
+				&includes[]=cover_art\
+				&contentRating[]=safe\
+				&contentRating[]=suggestive\
+				&contentRating[]=erotica\
+				&contentRating[]=pornographic\
+				{manga_ids}"
+		))?
+		.send()?
+		.get_json::<DexResponse<Vec<DexManga>>>()?
+		.data
+		.into_iter()
+		.map(|value| value.into_basic_manga())
+		.collect::<Vec<Manga>>();
+
+		let entries = chapters
+			.into_iter()
+			.filter_map(|value| {
+				let manga_id = value.manga_id()?;
+				let manga = manga.iter().find(|m| m.key == manga_id)?.clone();
+				Some(MangaWithChapter {
+					manga,
+					chapter: value.into(),
+				})
+			})
+			.collect();
+
+		Ok((entries, has_next_page))
+	}
+
+	// get a title's reading status, if it's in the library
+	fn get_reading_status(&self, manga_id: &str) -> Result<Option<String>> {
+		let json: Value = Request::get(format!("{API_URL}/manga/{manga_id}/status"))?
+			.authed_send()?
+			.get_json()?;
+		Ok(json
+			.get("status")
+			.and_then(|status| status.as_str())
+			.map(String::from))
+	}
+
+	// set a title's reading status, or remove it if the status is none
+	fn set_reading_status(&self, manga_id: &str, status: Option<String>) -> Result<()> {
+		let status = match status {
+			Some(status) if LIBRARY_STATUSES.iter().any(|(id, _)| *id == status) => {
+				Value::from(status)
+			}
+			_ => Value::Null,
+		};
+		let body = json!({ "status": status }).to_string();
+		let status_code = Request::post(format!("{API_URL}/manga/{manga_id}/status"))?
+			.header("Content-Type", "application/json")
+			.body(body)
+			.authed_send()?
+			.status_code();
+		if status_code != 200 {
+			bail!("Failed to update reading status");
+		}
+		Ok(())
+	}
+}
+
+// set the reading status of the title last opened
+impl NotificationHandler for MangaDex {
+	fn handle_notification(&self, notification: String) {
+		if notification != "setReadingStatus" {
+			return;
+		}
+		let Some((manga_id, title)) = settings::get_last_title() else {
+			return;
+		};
+		let status = settings::get_reading_status();
+		let result = match self.set_reading_status(&manga_id, status.clone()) {
+			Ok(()) => match status_name(status.as_deref()) {
+				Some(name) => format!("Set the reading status of {title} to {name}."),
+				None => format!("Removed {title} from the library."),
+			},
+			Err(err) => format!("Failed to update the reading status of {title}: {err:?}"),
+		};
+		settings::set_reading_status_result(result);
+	}
 }
 
-// show the library listing if we're logged in
+fn status_name(status: Option<&str>) -> Option<&'static str> {
+	let status = status?;
+	LIBRARY_STATUSES
+		.iter()
+		.find(|(id, _)| *id == status)
+		.map(|(_, name)| *name)
+}
+
+// change the reading status of the title last opened
+impl DynamicSettings for MangaDex {
+	fn get_dynamic_settings(&self) -> Result<Vec<Setting>> {
+		let Some((manga_id, title)) =
+			settings::get_last_title().filter(|_| settings::is_logged_in())
+		else {
+			return Ok(Vec::new());
+		};
+
+		let mut footer = match self.get_reading_status(&manga_id) {
+			Ok(status) => match status_name(status.as_deref()) {
+				Some(name) => format!("{title} is in your library as {name}."),
+				None => format!("{title} is not in your library."),
+			},
+			Err(_) => format!("Change the reading status of {title}."),
+		};
+		if let Some(result) = settings::get_reading_status_result() {
+			footer = format!("{footer}\n{result}");
+		}
+
+		let (mut values, mut titles): (Vec<_>, Vec<_>) = LIBRARY_STATUSES
+			.iter()
+			.map(|(id, name)| ((*id).into(), (*name).into()))
+			.unzip();
+		values.push("".into());
+		titles.push("None".into());
+
+		Ok(vec![
+			GroupSetting {
+				key: "readingStatusGroup".into(),
+				title: "Reading Status".into(),
+				items: vec![
+					SelectSetting {
+						key: "readingStatus".into(),
+						title: "Reading Status".into(),
+						values,
+						titles: Some(titles),
+						default: Some("reading".into()),
+						..Default::default()
+					}
+					.into(),
+					ButtonSetting {
+						key: "setReadingStatus".into(),
+						title: "Set Reading Status".into(),
+						notification: Some("setReadingStatus".into()),
+						refreshes: Some(vec!["settings".into(), "listings".into()]),
+						..Default::default()
+					}
+					.into(),
+				],
+				footer: Some(footer.into()),
+				..Default::default()
+			}
+			.into(),
+		])
+	}
+}
+
+// show the library listings if we're logged in
 impl DynamicListings for MangaDex {
 	fn get_dynamic_listings(&self) -> Result<Vec<Listing>> {
-		if settings::is_logged_in() {
-			Ok(Vec::from([Listing {
-				id: String::from("library"),
-				name: String::from("Library"),
-				kind: ListingKind::Default,
-			}]))
-		} else {
-			Ok(Vec::new())
+		if !settings::is_logged_in() {
+			return Ok(Vec::new());
 		}
+		let mut listings = Vec::from([Listing {
+			id: String::from("follows"),
+			name: String::from("Followed Updates"),
+			kind: ListingKind::Default,
+		}]);
+		listings.extend(LIBRARY_STATUSES.iter().map(|(id, name)| Listing {
+			id: format!("{LIBRARY_PREFIX}{id}"),
+			name: String::from(*name),
+			kind: ListingKind::Default,
+		}));
+		Ok(listings)
 	}
 }
 
@@ -640,23 +870,117 @@ impl DeepLinkHandler for MangaDex {
 }
 
 impl ImageRequestProvider for MangaDex {
-	fn get_image_request(
-		&self,
-		url: String,
-		_context: Option<aidoku::PageContext>,
-	) -> Result<Request> {
+	fn get_image_request(&self, url: String, context: Option<PageContext>) -> Result<Request> {
+		// use the replacement server if the chapter's original one failed
+		let url = context
+			.as_ref()
+			.and_then(|context| {
+				let server = self
+					.image_servers
+					.borrow()
+					.get(context.get("chapter")?)
+					.cloned()?;
+				Some(format!("{server}/{}", context.get("file")?))
+			})
+			.unwrap_or(url);
+
+		// timed for the at-home delivery report once the image is processed
+		if let Some(chapter_id) = context.as_ref().and_then(|context| context.get("chapter")) {
+			self.request_times
+				.borrow_mut()
+				.insert(url.clone(), (chapter_id.clone(), athome::now_ms()));
+		}
+
 		Ok(Request::get(url)?
 			.header("User-Agent", "Aidoku")
 			.header("Referer", REFERER))
 	}
 }
 
+impl PageImageProcessor for MangaDex {
+	fn process_page_image(
+		&self,
+		response: ImageResponse,
+		context: Option<PageContext>,
+	) -> Result<ImageRef> {
+		let url = response.request.url.clone().unwrap_or_default();
+		let duration = self
+			.request_times
+			.borrow_mut()
+			.remove(&url)
+			.map(|(_, start)| athome::now_ms() - start)
+			.unwrap_or_default();
+		let success = (200..300).contains(&response.code);
+		let cached = response
+			.headers
+			.get("X-Cache")
+			.is_some_and(|value| value.starts_with("HIT"));
+		let bytes = response
+			.headers
+			.get("Content-Length")
+			.and_then(|value| value.parse::<usize>().ok())
+			.unwrap_or_else(|| response.image.data().len());
+		athome::report(&url, success, cached, bytes, duration);
+
+		if success {
+			return Ok(response.image);
+		}
+
+		// the node failed, so load the image from a fresh at-home server
+		let (Some(chapter_id), Some(file)) = (
+			context.as_ref().and_then(|context| context.get("chapter")),
+			context.as_ref().and_then(|context| context.get("file")),
+		) else {
+			bail!("Failed to load image");
+		};
+		// the replacement server is shared by the chapter's other failing pages, and only
+		// requested again if it failed too
+		let replacement = self
+			.image_servers
+			.borrow()
+			.get(chapter_id)
+			.filter(|server| !url.starts_with(server.as_str()))
+			.cloned();
+		let base_url = match replacement {
+			Some(base_url) => base_url,
+			None => {
+				let (base_url, _) = athome::get_chapter_server(chapter_id)?;
+				self.image_servers
+					.borrow_mut()
+					.insert(chapter_id.clone(), base_url.clone());
+				base_url
+			}
+		};
+
+		let url = format!("{base_url}/{file}");
+		let start = athome::now_ms();
+		let mut response = Request::get(&url)?
+			.header("User-Agent", "Aidoku")
+			.header("Referer", REFERER)
+			.send()?;
+		let success = (200..300).contains(&response.status_code());
+		let cached = response
+			.get_header("X-Cache")
+			.is_some_and(|value| value.starts_with("HIT"));
+		let data = response.get_data()?;
+		athome::report(&url, success, cached, data.len(), athome::now_ms() - start);
+
+		if !success {
+			bail!("Failed to load image");
+		}
+		Ok(ImageRef::new(&data))
+	}
+}
+
 register_source!(
 	MangaDex,
 	Home,
 	ListingProvider,
 	DynamicListings,
+	DynamicSettings,
 	AlternateCoverProvider,
 	DeepLinkHandler,
-	ImageRequestProvider
+	ImageRequestProvider,
+	PageImageProcessor,
+	NotificationHandler
 );
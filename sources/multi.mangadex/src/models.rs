@@ -366,6 +366,36 @@ impl<'a> DexChapter<'a> {
 		})
 	}
 
+	// scanlation group ids, or the uploader id for chapters without a group
+	pub fn group_ids(&self) -> Vec<&'a str> {
+		let groups: Vec<&'a str> = self
+			.relationships
+			.iter()
+			.filter(|r| r.r#type == "scanlation_group")
+			.map(|r| r.id)
+			.collect();
+		if groups.is_empty() {
+			self.relationships
+				.iter()
+				.filter(|r| r.r#type == "user")
+				.map(|r| r.id)
+				.collect()
+		} else {
+			groups
+		}
+	}
+
+	pub fn is_official(&self) -> bool {
+		self.relationships.iter().any(|r| {
+			r.r#type == "scanlation_group"
+				&& r.attributes
+					.as_ref()
+					.and_then(|v| v.get("official"))
+					.and_then(|v| v.as_bool())
+					.unwrap_or(false)
+		})
+	}
+
 	pub fn scanlators(&self) -> Vec<String> {
 		let scanlation_groups: Vec<String> = self
 			.relationships
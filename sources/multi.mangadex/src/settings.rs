@@ -18,6 +18,12 @@ const BLOCKED_UUIDS_KEY: &str = "blockedUUIDs";
 const FORCE_PORT_KEY: &str = "standardHttpsPort";
 const DATA_SAVER_KEY: &str = "dataSaver";
 const LOCKED_CHAPTERS_KEY: &str = "lockedChapters";
+const PREFERRED_GROUPS_KEY: &str = "preferredGroups";
+const DEDUPED_CHAPTER_KEY: &str = "dedupedChapter";
+const LAST_TITLE_KEY: &str = "lastTitle";
+const LAST_TITLE_NAME_KEY: &str = "lastTitleName";
+const READING_STATUS_KEY: &str = "readingStatus";
+const READING_STATUS_RESULT_KEY: &str = "readingStatusResult";
 const TOKEN_KEY: &str = "login";
 const CODE_VERIFIER_KEY: &str = "login.codeVerifier";
 
@@ -102,6 +108,14 @@ pub fn get_blocked_uuids() -> Result<String> {
 		}))
 }
 
+pub fn get_preferred_groups() -> Vec<String> {
+	defaults_get::<Vec<String>>(PREFERRED_GROUPS_KEY).unwrap_or_default()
+}
+
+pub fn deduped_chapter() -> bool {
+	defaults_get::<bool>(DEDUPED_CHAPTER_KEY).unwrap_or(false)
+}
+
 pub fn get_force_port() -> bool {
 	defaults_get::<bool>(FORCE_PORT_KEY).unwrap_or(false)
 }
@@ -118,6 +132,37 @@ pub fn get_cover_quality() -> String {
 	defaults_get::<String>(COVER_QUALITY_KEY).unwrap_or_default()
 }
 
+// the id and name of the title whose details were last opened, to change its reading status
+pub fn get_last_title() -> Option<(String, String)> {
+	let id = defaults_get::<String>(LAST_TITLE_KEY).filter(|id| !id.is_empty())?;
+	let name = defaults_get::<String>(LAST_TITLE_NAME_KEY).unwrap_or_default();
+	Some((id, name))
+}
+
+pub fn set_last_title(id: &str, name: &str) {
+	if get_last_title().is_none_or(|(last_id, _)| last_id != id) {
+		defaults_set(READING_STATUS_RESULT_KEY, DefaultValue::Null);
+	}
+	defaults_set(LAST_TITLE_KEY, DefaultValue::String(String::from(id)));
+	defaults_set(
+		LAST_TITLE_NAME_KEY,
+		DefaultValue::String(String::from(name)),
+	);
+}
+
+pub fn get_reading_status() -> Option<String> {
+	defaults_get::<String>(READING_STATUS_KEY).filter(|status| !status.is_empty())
+}
+
+// the result of the last reading status change, shown in settings
+pub fn get_reading_status_result() -> Option<String> {
+	defaults_get::<String>(READING_STATUS_RESULT_KEY)
+}
+
+pub fn set_reading_status_result(result: String) {
+	defaults_set(READING_STATUS_RESULT_KEY, DefaultValue::String(result));
+}
+
 pub fn is_logged_in() -> bool {
 	defaults_get_json::<TokenResponse>(TOKEN_KEY).is_ok()
 }
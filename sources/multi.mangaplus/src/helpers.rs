@@ -1,11 +1,14 @@
 use aidoku::{
-	alloc::{String, string::ToString},
-	imports::std::current_date,
-	prelude::format,
+	Result,
+	alloc::{String, Vec, string::ToString},
+	imports::{net::Request, std::current_date},
+	prelude::*,
 };
+use hashbrown::HashSet;
 
+use crate::models::{Language, MangaPlusResponse, Title, WebHomeViewV4};
 use crate::settings;
-use crate::{MOBILE_API_URL, WEB_API_URL};
+use crate::{BASE_URL, MOBILE_API_URL, USER_AGENT, WEB_API_URL};
 
 pub fn get_api_url() -> String {
 	if settings::get_mobile() {
@@ -48,3 +51,68 @@ pub fn uuid() -> String {
 	let uuid = uuid::Builder::from_random_bytes(bytes).into_uuid();
 	uuid.to_string()
 }
+
+pub fn request(url: &str) -> Result<MangaPlusResponse> {
+	Ok(Request::get(url)?
+		.header("Referer", &format!("{BASE_URL}/"))
+		.header("User-Agent", USER_AGENT)
+		.header("Session-Token", &uuid())
+		.json_owned::<MangaPlusResponse>()?)
+}
+
+pub fn get_home_view() -> Result<WebHomeViewV4> {
+	let url = format!("{WEB_API_URL}/web/web_homeV4?lang=eng&clang=eng&format=json");
+	request(&url)?
+		.result_or_error("Failed to fetch home data")?
+		.web_home_view_v4
+		.ok_or(error!("Failed to fetch home view"))
+}
+
+// ranking types: hottest, trending, completed
+pub fn get_ranking(kind: &str) -> Result<Vec<Title>> {
+	let url = format!(
+		"{}/title_list/rankingV2?lang=eng&type={kind}&clang=eng&format=json{}",
+		get_api_url(),
+		build_auth_params()
+	);
+	let ranking = request(&url)?
+		.result_or_error("Failed to fetch ranking")?
+		.title_ranking_view_v2
+		.ok_or(error!("Failed to fetch ranking"))?;
+	Ok(ranking
+		.ranked_titles
+		.into_iter()
+		.flat_map(|group| group.titles)
+		.collect())
+}
+
+pub fn get_featured() -> Result<Vec<Title>> {
+	let url = format!(
+		"{}/featured?lang=eng&clang=eng&format=json{}",
+		get_api_url(),
+		build_auth_params()
+	);
+	let featured = request(&url)?
+		.result_or_error("Failed to fetch featured titles")?
+		.featured_titles_view_v2
+		.ok_or(error!("Failed to fetch featured titles"))?;
+	Ok(featured
+		.contents
+		.into_iter()
+		.filter_map(|content| content.title_list)
+		.flat_map(|list| list.featured_titles)
+		.collect())
+}
+
+// removes duplicate titles and titles not in the selected languages
+pub fn filter_titles<I: IntoIterator<Item = Title>>(
+	titles: I,
+	languages: &[Language],
+) -> Vec<Title> {
+	let mut seen = HashSet::new();
+	titles
+		.into_iter()
+		.filter(|title| title.language.is_none_or(|lang| languages.contains(&lang)))
+		.filter(|title| seen.insert(title.title_id))
+		.collect()
+}
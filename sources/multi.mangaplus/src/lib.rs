@@ -5,17 +5,16 @@ use aidoku::{
 	PageContent, PageContext, PageImageProcessor, Result, Source,
 	alloc::{String, Vec, string::ToString, vec},
 	helpers::uri::decode_uri,
-	imports::{canvas::ImageRef, net::Request},
+	imports::canvas::ImageRef,
 	prelude::*,
 };
 use core::cell::RefCell;
-use hashbrown::HashSet;
 
 mod helpers;
 mod models;
 mod settings;
 
-use models::{MangaPlusResponse, Title};
+use models::{Title, UpdatedTitleV2Group};
 
 const BASE_URL: &str = "https://mangaplus.shueisha.co.jp";
 const WEB_API_URL: &str = "https://jumpg-webapi.tokyo-cdn.com/api";
@@ -23,6 +22,16 @@ const MOBILE_API_URL: &str = "https://jumpg-api.tokyo-cdn.com/api";
 const USER_AGENT: &str = "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/141.0.0.0 Safari/537.36";
 const ITEMS_PER_PAGE: usize = 20;
 
+const WEEKDAYS: [&str; 7] = [
+	"Monday",
+	"Tuesday",
+	"Wednesday",
+	"Thursday",
+	"Friday",
+	"Saturday",
+	"Sunday",
+];
+
 #[derive(Default)]
 struct MangaPlus {
 	directory: RefCell<Vec<Title>>,
@@ -78,12 +87,7 @@ impl Source for MangaPlus {
 				)
 			};
 
-			let result = Request::get(url())?
-				.header("Referer", &format!("{BASE_URL}/"))
-				.header("User-Agent", USER_AGENT)
-				.header("Session-Token", &helpers::uuid())
-				.json_owned::<MangaPlusResponse>()?
-				.result_or_error("Failed to fetch title list")?;
+			let result = helpers::request(&url())?.result_or_error("Failed to fetch title list")?;
 
 			let languages = settings::get_languages()?;
 
@@ -168,12 +172,7 @@ impl Source for MangaPlus {
 			manga.key,
 			helpers::build_auth_params()
 		);
-		let result = Request::get(&url)?
-			.header("Referer", &format!("{BASE_URL}/"))
-			.header("User-Agent", USER_AGENT)
-			.header("Session-Token", &helpers::uuid())
-			.json_owned::<MangaPlusResponse>()?
-			.result_or_error("Failed to fetch title")?;
+		let result = helpers::request(&url)?.result_or_error("Failed to fetch title")?;
 
 		let Some(details) = result.title_detail_view else {
 			bail!("Failed to fetch title details");
@@ -208,12 +207,7 @@ impl Source for MangaPlus {
 			settings::get_image_quality(),
 			helpers::build_auth_params()
 		);
-		let result = Request::get(&url)?
-			.header("Referer", &format!("{BASE_URL}/"))
-			.header("User-Agent", USER_AGENT)
-			.header("Session-Token", &helpers::uuid())
-			.json_owned::<MangaPlusResponse>()?
-			.result_or_error("Failed to fetch title")?;
+		let result = helpers::request(&url)?.result_or_error("Failed to fetch title")?;
 
 		let Some(viewer) = result.manga_viewer else {
 			bail!("Failed to fetch manga viewer");
@@ -279,55 +273,23 @@ impl PageImageProcessor for MangaPlus {
 impl ListingProvider for MangaPlus {
 	fn get_manga_list(&self, listing: Listing, page: i32) -> Result<MangaPageResult> {
 		if page == 1 {
-			let url = format!("{WEB_API_URL}/web/web_homeV4?lang=eng&clang=eng&format=json");
-			let result = Request::get(url)?
-				.header("Referer", &format!("{BASE_URL}/"))
-				.header("User-Agent", USER_AGENT)
-				.header("Session-Token", &helpers::uuid())
-				.json_owned::<MangaPlusResponse>()?
-				.result_or_error("Failed to fetch home data")?;
-
-			let Some(home_view) = result.web_home_view_v4 else {
-				bail!("Failed to fetch home view");
+			let titles = match listing.id.as_str() {
+				"Updates" => helpers::get_home_view()?
+					.groups
+					.into_iter()
+					.flat_map(|group| group.title_groups)
+					.flat_map(|group| group.titles)
+					.map(|title| title.title)
+					.collect(),
+				"Ranking" | "Hottest" => helpers::get_ranking("hottest")?,
+				"Trending" => helpers::get_ranking("trending")?,
+				"Completed" => helpers::get_ranking("completed")?,
+				"Featured" => helpers::get_featured()?,
+				_ => bail!("Invalid listing"),
 			};
 
 			let languages = settings::get_languages()?;
-
-			let mut seen = HashSet::new();
-
-			match listing.id.as_str() {
-				"Updates" => {
-					*self.directory.borrow_mut() = home_view
-						.groups
-						.into_iter()
-						.flat_map(|group| group.title_groups)
-						.flat_map(|group| group.titles)
-						.map(|title| title.title)
-						.filter(|title| title.language.is_none_or(|lang| languages.contains(&lang)))
-						.filter(|title| seen.insert(title.title_id))
-						.collect();
-				}
-				// it doesn't look like the home view has featured results anymore
-				// "Featured" => {
-				// 	*self.directory.borrow_mut() = home_view
-				// 		.featured_title_lists
-				// 		.into_iter()
-				// 		.flat_map(|list| list.featured_titles)
-				// 		.filter(|title| title.language.is_none_or(|lang| languages.contains(&lang)))
-				// 		.filter(|title| seen.insert(title.title_id))
-				// 		.collect();
-				// }
-				"Ranking" => {
-					*self.directory.borrow_mut() = home_view
-						.ranked_titles
-						.into_iter()
-						.flat_map(|group| group.titles)
-						.filter(|title| title.language.is_none_or(|lang| languages.contains(&lang)))
-						.filter(|title| seen.insert(title.title_id))
-						.collect();
-				}
-				_ => bail!("Invalid listing"),
-			}
+			*self.directory.borrow_mut() = helpers::filter_titles(titles, &languages);
 		}
 
 		Ok(self.parse_directory(page))
@@ -336,17 +298,7 @@ impl ListingProvider for MangaPlus {
 
 impl Home for MangaPlus {
 	fn get_home(&self) -> Result<HomeLayout> {
-		let url = format!("{WEB_API_URL}/web/web_homeV4?lang=eng&clang=eng&format=json");
-		let result = Request::get(url)?
-			.header("Referer", &format!("{BASE_URL}/"))
-			.header("User-Agent", USER_AGENT)
-			.header("Session-Token", &helpers::uuid())
-			.json_owned::<MangaPlusResponse>()?
-			.result_or_error("Failed to fetch home data")?;
-
-		let Some(home_view) = result.web_home_view_v4 else {
-			bail!("Failed to fetch home view");
-		};
+		let home_view = helpers::get_home_view()?;
 
 		let mut components = Vec::new();
 
@@ -389,21 +341,27 @@ impl Home for MangaPlus {
 
 		let languages = settings::get_languages()?;
 
+		// flattens an update group into links for the selected languages
+		let group_entries = |group: &UpdatedTitleV2Group| -> Vec<Link> {
+			helpers::filter_titles(
+				group
+					.title_groups
+					.iter()
+					.flat_map(|group| group.titles.iter())
+					.map(|title| title.title.clone()),
+				&languages,
+			)
+			.into_iter()
+			.map(|title| Manga::from(title).into())
+			.collect()
+		};
+
 		if let Some(daily_updates) = home_view
 			.groups
 			.iter()
 			.find(|g| g.group_name == "updates_latest_title_/_updates_past_24_title")
 		{
-			let mut seen = HashSet::new();
-			let entries: Vec<Link> = daily_updates
-				.title_groups
-				.iter()
-				.flat_map(|group| group.titles.clone())
-				.map(|title| title.title)
-				.filter(|title| title.language.is_none_or(|lang| languages.contains(&lang)))
-				.filter(|title| seen.insert(title.title_id))
-				.map(|title| Manga::from(title).into())
-				.collect();
+			let entries = group_entries(daily_updates);
 			if !entries.is_empty() {
 				components.push(HomeComponent {
 					title: Some("Daily Updates".into()),
@@ -420,16 +378,39 @@ impl Home for MangaPlus {
 			}
 		}
 
-		let mut seen = HashSet::new();
-		let entries: Vec<Link> = home_view
-			.ranked_titles
-			.iter()
-			.flat_map(|group| group.titles.clone())
-			.filter(|title| title.language.is_none_or(|lang| languages.contains(&lang)))
-			.filter(|title| seen.insert(title.title_id))
-			.map(|title| Manga::from(title).into())
-			.take(50)
-			.collect();
+		// weekly update schedule, one scroller per weekday group
+		for weekday in WEEKDAYS {
+			let Some(group) = home_view.groups.iter().find(|g| {
+				g.group_name
+					.to_lowercase()
+					.contains(&weekday.to_lowercase())
+			}) else {
+				continue;
+			};
+			let entries = group_entries(group);
+			if !entries.is_empty() {
+				components.push(HomeComponent {
+					title: Some(weekday.into()),
+					value: HomeComponentValue::Scroller {
+						entries,
+						listing: None,
+					},
+					..Default::default()
+				});
+			}
+		}
+
+		let entries: Vec<Link> = helpers::filter_titles(
+			home_view
+				.ranked_titles
+				.iter()
+				.flat_map(|group| group.titles.clone()),
+			&languages,
+		)
+		.into_iter()
+		.map(|title| Manga::from(title).into())
+		.take(50)
+		.collect();
 		if !entries.is_empty() {
 			components.push(HomeComponent {
 				title: Some("Hottest".into()),
@@ -438,7 +419,7 @@ impl Home for MangaPlus {
 					page_size: Some(10),
 					entries,
 					listing: Some(Listing {
-						id: "Ranking".into(),
+						id: "Hottest".into(),
 						name: "Hottest".into(),
 						..Default::default()
 					}),
@@ -447,6 +428,28 @@ impl Home for MangaPlus {
 			});
 		}
 
+		// the featured titles come from a separate endpoint, so don't fail the home page over them
+		let entries: Vec<Link> = helpers::get_featured()
+			.map(|titles| helpers::filter_titles(titles, &languages))
+			.unwrap_or_default()
+			.into_iter()
+			.map(|title| Manga::from(title).into())
+			.collect();
+		if !entries.is_empty() {
+			components.push(HomeComponent {
+				title: Some("Featured".into()),
+				value: HomeComponentValue::Scroller {
+					entries,
+					listing: Some(Listing {
+						id: "Featured".into(),
+						name: "Featured".into(),
+						..Default::default()
+					}),
+				},
+				..Default::default()
+			});
+		}
+
 		Ok(HomeLayout { components })
 	}
 }
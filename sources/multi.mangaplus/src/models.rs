@@ -54,19 +54,32 @@ pub struct Popup {
 #[derive(Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SuccessResult {
-	// pub is_featured_updated: Option<bool>,
-	// pub title_ranking_view_v2: Option<TitleRankingViewV2>,
+	pub title_ranking_view_v2: Option<TitleRankingViewV2>,
+	pub featured_titles_view_v2: Option<FeaturedTitlesViewV2>,
 	pub title_detail_view: Option<TitleDetailView>,
 	pub manga_viewer: Option<MangaViewer>,
 	pub all_titles_view_v2: Option<AllTitlesViewV2>,
 	pub web_home_view_v4: Option<WebHomeViewV4>,
 }
 
-// #[derive(Deserialize)]
-// #[serde(rename_all = "camelCase")]
-// pub struct TitleRankingViewV2 {
-// 	pub ranked_titles: Vec<RankedTitle>,
-// }
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TitleRankingViewV2 {
+	#[serde(default)]
+	pub ranked_titles: Vec<RankedTitle>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default)]
+pub struct FeaturedTitlesViewV2 {
+	pub contents: Vec<FeaturedTitleContent>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeaturedTitleContent {
+	pub title_list: Option<FeaturedTitleList>,
+}
 
 #[derive(Deserialize)]
 pub struct RankedTitle {
@@ -111,11 +124,12 @@ pub struct BannerAction {
 	pub url: String,
 }
 
-// #[derive(Deserialize)]
-// #[serde(rename_all = "camelCase")]
-// pub struct FeaturedTitleList {
-// 	pub featured_titles: Vec<Title>,
-// }
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeaturedTitleList {
+	#[serde(default)]
+	pub featured_titles: Vec<Title>,
+}
 
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
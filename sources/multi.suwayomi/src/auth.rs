@@ -0,0 +1,118 @@
+use crate::graphql::GraphQLQuery;
+use crate::models::{GraphQLResponse, LoginResponse, RefreshTokenResponse};
+use crate::settings::{self, AuthMode};
+use aidoku::{
+	Result,
+	alloc::{String, string::ToString},
+	imports::net::{Request, Response},
+	prelude::*,
+};
+use base64::{Engine, engine::general_purpose::STANDARD};
+
+pub trait AuthedRequest {
+	fn authed(self) -> Result<Request>;
+	fn authed_send(self) -> Result<Response>;
+}
+
+impl AuthedRequest for Request {
+	// adds the authorization header for the configured auth mode
+	fn authed(self) -> Result<Self> {
+		match settings::auth_mode() {
+			AuthMode::None => Ok(self),
+			AuthMode::Basic => {
+				let (username, password) =
+					settings::get_credentials().ok_or_else(|| error!("Login required."))?;
+				Ok(self.header("Authorization", &basic_header(&username, &password)))
+			}
+			AuthMode::UiLogin => {
+				let token = match settings::get_access_token() {
+					Some(token) => token,
+					None => login()?,
+				};
+				Ok(self.header("Authorization", &format!("Bearer {token}")))
+			}
+		}
+	}
+
+	// sends the request, refreshing the access token once if it has expired
+	fn authed_send(self) -> Result<Response> {
+		let mut response = self.authed()?.send()?;
+
+		if response.status_code() == 401 && settings::auth_mode() == AuthMode::UiLogin {
+			let token = refresh_access_token()?;
+			response = response
+				.into_request()
+				.header("Authorization", &format!("Bearer {token}"))
+				.send()?;
+		}
+
+		if response.status_code() == 401 {
+			bail!("Unauthorized: check the server login in settings.");
+		}
+
+		Ok(response)
+	}
+}
+
+pub fn basic_header(username: &str, password: &str) -> String {
+	let encoded = STANDARD.encode(format!("{username}:{password}"));
+	format!("Basic {encoded}")
+}
+
+fn graphql_post(gql: GraphQLQuery, variables: serde_json::Value) -> Result<Request> {
+	let base_url = settings::get_base_url()?;
+	Ok(Request::post(format!("{base_url}/api/graphql"))?
+		.header("Content-Type", "application/json")
+		.body(gql.body(Some(variables)).to_string()))
+}
+
+// logs in with the ui login credentials, storing and returning the new access token
+pub fn login_with(username: &str, password: &str) -> Result<String> {
+	let mut response = graphql_post(
+		GraphQLQuery::LOGIN,
+		serde_json::json!({
+			"username": username,
+			"password": password,
+		}),
+	)?
+	.send()?;
+	if response.status_code() == 401 {
+		bail!("Invalid username or password.");
+	}
+	let tokens = response
+		.get_json::<GraphQLResponse<LoginResponse>>()?
+		.data
+		.login;
+
+	settings::set_access_token(&tokens.access_token);
+	settings::set_refresh_token(&tokens.refresh_token);
+	Ok(tokens.access_token)
+}
+
+fn login() -> Result<String> {
+	let (username, password) =
+		settings::get_credentials().ok_or_else(|| error!("Login required."))?;
+	login_with(&username, &password)
+}
+
+fn refresh_access_token() -> Result<String> {
+	let Some(refresh_token) = settings::get_refresh_token() else {
+		return login();
+	};
+
+	let mut response = graphql_post(
+		GraphQLQuery::REFRESH_TOKEN,
+		serde_json::json!({ "refreshToken": refresh_token }),
+	)?
+	.send()?;
+
+	// the refresh token has expired too, so log in again
+	let Ok(data) = response.get_json::<GraphQLResponse<RefreshTokenResponse>>() else {
+		settings::clear_tokens();
+		return login();
+	};
+
+	let token = data.data.refresh_token.access_token;
+	settings::set_access_token(&token);
+	Ok(token)
+}
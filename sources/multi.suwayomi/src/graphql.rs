@@ -3,8 +3,8 @@ pub struct GraphQLQuery {
 	pub query: &'static str,
 }
 
-const GET_SEARCH_MANGA_LIST: &str = r#"query GET_SEARCH_MANGA_LIST($condition: MangaConditionInput, $order: [MangaOrderInput!], $filter: MangaFilterInput) {
-	mangas(condition: $condition, order: $order, filter: $filter) {
+const GET_SEARCH_MANGA_LIST: &str = r#"query GET_SEARCH_MANGA_LIST($condition: MangaConditionInput, $order: [MangaOrderInput!], $filter: MangaFilterInput, $first: Int, $offset: Int) {
+	mangas(condition: $condition, order: $order, filter: $filter, first: $first, offset: $offset) {
 		nodes {
 			id
 			title
@@ -14,6 +14,9 @@ const GET_SEARCH_MANGA_LIST: &str = r#"query GET_SEARCH_MANGA_LIST($condition: M
 			genre
 			status
 		}
+		pageInfo {
+			hasNextPage
+		}
 	}
 }"#;
 
@@ -26,6 +29,9 @@ const GET_MANGA_CHAPTERS: &str = r#"query GET_MANGA_CHAPTERS($mangaId: Int!) {
 			scanlator
 			uploadDate
 			sourceOrder
+			isRead
+			isBookmarked
+			lastPageRead
 			manga {
 				source {
 					displayName
@@ -44,6 +50,7 @@ const GET_CHAPTER_PAGES: &str = r#"mutation GET_CHAPTER_PAGES($input: FetchChapt
 const GET_MANGA_DESCRIPTION: &str = r#"query GET_MANGA_DESCRIPTION($mangaId: Int!) {
 	manga(id: $mangaId) {
 		description
+		inLibrary
 	}
 }
 "#;
@@ -57,7 +64,100 @@ const GET_CATEGORIES: &str = r#"query GET_CATEGORIES {
 	}
 }"#;
 
+const GET_SOURCES: &str = r#"query GET_SOURCES {
+	sources {
+		nodes {
+			id
+			displayName
+			lang
+			supportsLatest
+		}
+	}
+}"#;
+
+const FETCH_SOURCE_MANGA: &str = r#"mutation FETCH_SOURCE_MANGA($input: FetchSourceMangaInput!) {
+	fetchSourceManga(input: $input) {
+		hasNextPage
+		mangas {
+			id
+			title
+			thumbnailUrl
+			author
+			artist
+			genre
+			status
+		}
+	}
+}"#;
+
+const FETCH_CHAPTERS: &str = r#"mutation FETCH_CHAPTERS($input: FetchChaptersInput!) {
+	fetchChapters(input: $input) {
+		chapters {
+			id
+			name
+			chapterNumber
+			scanlator
+			uploadDate
+			sourceOrder
+			isRead
+			isBookmarked
+			lastPageRead
+			manga {
+				source {
+					displayName
+				}
+			}
+		}
+	}
+}"#;
+
+const LOGIN: &str = r#"mutation LOGIN($username: String!, $password: String!) {
+	login(input: {username: $username, password: $password}) {
+		accessToken
+		refreshToken
+	}
+}"#;
+
+const REFRESH_TOKEN: &str = r#"mutation REFRESH_TOKEN($refreshToken: String!) {
+	refreshToken(input: {refreshToken: $refreshToken}) {
+		accessToken
+	}
+}"#;
+
+const UPDATE_MANGA: &str = r#"mutation UPDATE_MANGA($id: Int!, $patch: UpdateMangaPatchInput!) {
+	updateManga(input: {id: $id, patch: $patch}) {
+		manga {
+			id
+			inLibrary
+		}
+	}
+}"#;
+
+const UPDATE_CHAPTERS: &str = r#"mutation UPDATE_CHAPTERS($ids: [Int!]!, $patch: UpdateChapterPatchInput!) {
+	updateChapters(input: {ids: $ids, patch: $patch}) {
+		chapters {
+			id
+			isRead
+			isBookmarked
+			lastPageRead
+		}
+	}
+}"#;
+
 impl GraphQLQuery {
+	pub fn body(&self, variables: Option<serde_json::Value>) -> serde_json::Value {
+		let mut body = serde_json::json!({
+			"operationName": self.operation_name,
+			"query": self.query,
+		});
+
+		if let Some(vars) = variables {
+			body["variables"] = vars;
+		}
+
+		body
+	}
+
 	pub const SEARCH_MANGA_LIST: Self = Self {
 		operation_name: "GET_SEARCH_MANGA_LIST",
 		query: GET_SEARCH_MANGA_LIST,
@@ -82,4 +182,39 @@ impl GraphQLQuery {
 		operation_name: "GET_CATEGORIES",
 		query: GET_CATEGORIES,
 	};
+
+	pub const SOURCES: Self = Self {
+		operation_name: "GET_SOURCES",
+		query: GET_SOURCES,
+	};
+
+	pub const UPDATE_CHAPTERS: Self = Self {
+		operation_name: "UPDATE_CHAPTERS",
+		query: UPDATE_CHAPTERS,
+	};
+
+	pub const FETCH_SOURCE_MANGA: Self = Self {
+		operation_name: "FETCH_SOURCE_MANGA",
+		query: FETCH_SOURCE_MANGA,
+	};
+
+	pub const FETCH_CHAPTERS: Self = Self {
+		operation_name: "FETCH_CHAPTERS",
+		query: FETCH_CHAPTERS,
+	};
+
+	pub const UPDATE_MANGA: Self = Self {
+		operation_name: "UPDATE_MANGA",
+		query: UPDATE_MANGA,
+	};
+
+	pub const LOGIN: Self = Self {
+		operation_name: "LOGIN",
+		query: LOGIN,
+	};
+
+	pub const REFRESH_TOKEN: Self = Self {
+		operation_name: "REFRESH_TOKEN",
+		query: REFRESH_TOKEN,
+	};
 }
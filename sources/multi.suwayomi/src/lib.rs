@@ -1,24 +1,34 @@
 #![no_std]
 extern crate alloc;
 
+mod auth;
 mod graphql;
 mod models;
 mod settings;
 
 const CATEGORY_FILTER_ID: &str = "CATEGORY";
+const SOURCE_FILTER_ID: &str = "source";
+const BROWSE_FILTER_ID: &str = "browse";
+const PAGE_SIZE: i32 = 50;
 
+use crate::auth::AuthedRequest;
 use crate::models::{
-	FetchChapterPagesResponse, GraphQLResponse, MangaOnlyDescriptionResponse, MultipleCategories,
-	MultipleChapters, MultipleMangas,
+	ChapterState, FetchChapterPagesResponse, FetchChaptersResponse, FetchSourceMangaResponse,
+	GraphQLResponse, MangaOnlyDescriptionResponse, MultipleCategories, MultipleChapters,
+	MultipleMangas, MultipleSources, RecentChapter, UpdateChaptersResponse, ViewedManga,
 };
+use crate::settings::AuthMode;
 use aidoku::imports::std::send_partial_result;
 use aidoku::{
-	AidokuError, BaseUrlProvider, Chapter, DynamicListings, FilterValue, Listing, ListingProvider,
-	Manga, MangaPageResult, Page, PageContent, Result, Source,
+	AidokuError, BaseUrlProvider, BasicLoginHandler, ButtonSetting, Chapter, DynamicFilters,
+	DynamicListings, DynamicSettings, Filter, FilterValue, GroupSetting, ImageRequestProvider,
+	Listing, ListingProvider, Manga, MangaPageResult, NotificationHandler, Page, PageContent,
+	PageContext, Result, SelectFilter, SelectSetting, Setting, Source,
 	alloc::{String, Vec},
 	imports::net::Request,
 	prelude::*,
 };
+use alloc::borrow::Cow;
 use alloc::string::ToString;
 use alloc::vec;
 
@@ -33,7 +43,8 @@ impl Suwayomi {
 		Request::post(format!("{base_url}/api/graphql"))?
 			.header("Content-Type", "application/json")
 			.body(body.to_string())
-			.json_owned::<GraphQLResponse<T>>()
+			.authed_send()?
+			.get_json::<GraphQLResponse<T>>()
 	}
 
 	fn execute_query<T>(
@@ -44,16 +55,59 @@ impl Suwayomi {
 	where
 		T: serde::de::DeserializeOwned,
 	{
-		let mut body = serde_json::json!({
-			"operationName": gql.operation_name,
-			"query": gql.query,
-		});
+		self.graphql_request(gql.body(variables))
+	}
 
-		if let Some(vars) = variables {
-			body["variables"] = vars;
-		}
+	// updates the read/bookmark state of chapters on the server, returning their new state
+	// the patch accepts `isRead`, `isBookmarked` and `lastPageRead`
+	fn update_chapters(&self, ids: &[i32], patch: serde_json::Value) -> Result<Vec<ChapterState>> {
+		let response = self.execute_query::<UpdateChaptersResponse>(
+			graphql::GraphQLQuery::UPDATE_CHAPTERS,
+			Some(serde_json::json!({
+				"ids": ids,
+				"patch": patch,
+			})),
+		)?;
+		Ok(response.data.update_chapters.chapters)
+	}
+
+	// browses an extension source through the server
+	// `kind` is one of `POPULAR`, `LATEST` or `SEARCH`
+	fn fetch_source_manga(
+		&self,
+		source_id: &str,
+		kind: &str,
+		query: Option<String>,
+		page: i32,
+	) -> Result<MangaPageResult> {
+		let response = self.execute_query::<FetchSourceMangaResponse>(
+			graphql::GraphQLQuery::FETCH_SOURCE_MANGA,
+			Some(serde_json::json!({
+				"input": {
+					"source": source_id,
+					"type": kind,
+					"page": page.max(1),
+					"query": query,
+				}
+			})),
+		)?;
 
-		self.graphql_request(body)
+		let base_url = settings::get_base_url()?;
+		let result = response.data.fetch_source_manga;
+		Ok(MangaPageResult {
+			has_next_page: result.has_next_page,
+			entries: result
+				.mangas
+				.into_iter()
+				.map(|m| m.into_manga(&base_url))
+				.collect(),
+		})
+	}
+
+	fn get_sources(&self) -> Result<Vec<models::SourceDto>> {
+		let response =
+			self.execute_query::<MultipleSources>(graphql::GraphQLQuery::SOURCES, None)?;
+		Ok(response.data.sources.nodes)
 	}
 }
 
@@ -65,9 +119,20 @@ impl Source for Suwayomi {
 	fn get_search_manga_list(
 		&self,
 		query: Option<String>,
-		_page: i32,
+		page: i32,
 		filters: Vec<FilterValue>,
 	) -> Result<MangaPageResult> {
+		// titles from an extension source are fetched through the server instead of the library
+		if let Some(source_id) = filters.iter().find_map(|filter| match filter {
+			FilterValue::Select { id, value } if id == BROWSE_FILTER_ID && !value.is_empty() => {
+				Some(value.clone())
+			}
+			_ => None,
+		}) {
+			let kind = if query.is_some() { "SEARCH" } else { "POPULAR" };
+			return self.fetch_source_manga(&source_id, kind, query, page);
+		}
+
 		let mut condition = serde_json::Map::new();
 		condition.insert("inLibrary".to_string(), serde_json::json!(true));
 
@@ -83,6 +148,8 @@ impl Source for Suwayomi {
 						0 => "TITLE",
 						1 => "IN_LIBRARY_AT",
 						2 => "LAST_FETCHED_AT",
+						3 => "UNREAD_COUNT",
+						4 => "LATEST_UPLOADED_CHAPTER",
 						_ => continue,
 					};
 					order.push(serde_json::json!({
@@ -100,6 +167,46 @@ impl Source for Suwayomi {
 							serde_json::json!({"equalTo": value})
 						};
 						manga_filter.insert("categoryId".to_string(), filter_value);
+					} else if value != 0 {
+						let key = match id.as_str() {
+							"unread" => "unreadCount",
+							"downloaded" => "downloadCount",
+							_ => continue,
+						};
+						manga_filter.insert(key.to_string(), serde_json::json!({"greaterThan": 0}));
+					}
+				}
+				FilterValue::Text { id, value } => {
+					let key = match id.as_str() {
+						"author" => "author",
+						"genre" => "genre",
+						_ => continue,
+					};
+					manga_filter.insert(
+						key.to_string(),
+						serde_json::json!({
+							"includesInsensitive": value
+						}),
+					);
+				}
+				FilterValue::Select { id, value } => {
+					if value.is_empty() {
+						continue;
+					}
+					match id.as_str() {
+						"status" => {
+							manga_filter.insert(
+								"status".to_string(),
+								serde_json::json!({"equalTo": value}),
+							);
+						}
+						SOURCE_FILTER_ID => {
+							manga_filter.insert(
+								"sourceId".to_string(),
+								serde_json::json!({"equalTo": value}),
+							);
+						}
+						_ => continue,
 					}
 				}
 				_ => continue,
@@ -125,6 +232,11 @@ impl Source for Suwayomi {
 			"filter".to_string(),
 			serde_json::Value::Object(manga_filter),
 		);
+		variables.insert("first".to_string(), serde_json::json!(PAGE_SIZE));
+		variables.insert(
+			"offset".to_string(),
+			serde_json::json!((page.max(1) - 1) * PAGE_SIZE),
+		);
 
 		let json_value = serde_json::Value::Object(variables);
 
@@ -134,15 +246,14 @@ impl Source for Suwayomi {
 		)?;
 
 		let base_url = settings::get_base_url()?;
+		let mangas = response.data.mangas;
 		Ok(MangaPageResult {
-			entries: response
-				.data
-				.mangas
+			has_next_page: mangas.page_info.is_some_and(|info| info.has_next_page),
+			entries: mangas
 				.nodes
 				.into_iter()
 				.map(|m| m.into_manga(&base_url))
 				.collect(),
-			has_next_page: false,
 		})
 	}
 
@@ -164,7 +275,16 @@ impl Source for Suwayomi {
 				})),
 			)?;
 
-			manga.description = Some(response.data.manga.description);
+			let details = response.data.manga;
+			manga.description = details.description;
+
+			// remembered for the "add to library" button in the settings, since details are
+			// also fetched for titles that are only being browsed
+			settings::set_last_viewed_manga(&ViewedManga {
+				id: manga_id,
+				title: manga.title.clone(),
+				in_library: details.in_library,
+			});
 
 			if needs_chapters {
 				send_partial_result(&manga);
@@ -178,12 +298,31 @@ impl Source for Suwayomi {
 				})),
 			)?;
 
+			let mut chapters = response.data.chapters.nodes;
+			// titles browsed from an extension source have no chapters stored yet
+			if chapters.is_empty() {
+				chapters = self
+					.execute_query::<FetchChaptersResponse>(
+						graphql::GraphQLQuery::FETCH_CHAPTERS,
+						Some(serde_json::json!({
+							"input": {
+								"mangaId": manga_id
+							}
+						})),
+					)?
+					.data
+					.fetch_chapters
+					.chapters;
+			}
+
+			// keeps the state shown for recently opened chapters in the settings up to date
+			settings::update_recent_chapters(
+				&chapters.iter().map(|c| c.state()).collect::<Vec<_>>(),
+			);
+
 			let base_url = settings::get_base_url()?;
 			manga.chapters = Some(
-				response
-					.data
-					.chapters
-					.nodes
+				chapters
 					.into_iter()
 					.map(|c| c.into_chapter(&base_url, manga_id))
 					.collect(),
@@ -193,7 +332,7 @@ impl Source for Suwayomi {
 		Ok(manga)
 	}
 
-	fn get_page_list(&self, _manga: Manga, chapter: Chapter) -> Result<Vec<Page>> {
+	fn get_page_list(&self, manga: Manga, chapter: Chapter) -> Result<Vec<Page>> {
 		let chapter_id = chapter
 			.key
 			.parse::<i32>()
@@ -209,17 +348,26 @@ impl Source for Suwayomi {
 		)?;
 
 		let base_url = settings::get_base_url()?;
-		Ok(response
-			.data
-			.fetch_chapter_pages
-			.pages
+		let pages = response.data.fetch_chapter_pages.pages;
+		// page lists are also fetched for downloads and preloading, so progress is only sent
+		// for the chapter picked in the settings
+		settings::add_recent_chapter(RecentChapter {
+			id: chapter_id,
+			manga_title: manga.title,
+			title: chapter
+				.title
+				.unwrap_or_else(|| match chapter.chapter_number {
+					Some(number) => format!("Chapter {number}"),
+					None => chapter.key,
+				}),
+			state: None,
+		});
+
+		Ok(pages
 			.into_iter()
-			.map(|url| {
-				let full_url = format!("{}{}", base_url, url);
-				Page {
-					content: PageContent::Url(full_url, None),
-					..Default::default()
-				}
+			.map(|url| Page {
+				content: PageContent::Url(format!("{}{}", base_url, url), None),
+				..Default::default()
 			})
 			.collect())
 	}
@@ -227,6 +375,11 @@ impl Source for Suwayomi {
 
 impl ListingProvider for Suwayomi {
 	fn get_manga_list(&self, listing: Listing, page: i32) -> Result<MangaPageResult> {
+		// extension source listings have ids in the form `POPULAR:<source id>`
+		if let Some((kind, source_id)) = listing.id.split_once(':') {
+			return self.fetch_source_manga(source_id, kind, None, page);
+		}
+
 		let category_id = listing
 			.id
 			.parse::<i32>()
@@ -258,10 +411,244 @@ impl DynamicListings for Suwayomi {
 		let categories = response.data.categories.nodes;
 		let total_count = categories.len();
 
-		Ok(categories
+		let mut listings: Vec<Listing> = categories
 			.into_iter()
 			.map(|c| c.into_listing(total_count))
-			.collect())
+			.collect();
+
+		// the category listings are still usable if the sources can't be fetched
+		if settings::source_listings()
+			&& let Ok(sources) = self.get_sources()
+		{
+			listings.extend(sources.into_iter().flat_map(|s| s.into_listings()));
+		}
+
+		Ok(listings)
+	}
+}
+
+impl DynamicFilters for Suwayomi {
+	fn get_dynamic_filters(&self) -> Result<Vec<Filter>> {
+		let sources: Vec<(String, String)> = self
+			.get_sources()?
+			.into_iter()
+			.map(|s| (s.display_name, s.id))
+			.collect();
+
+		let (options, ids): (Vec<Cow<'static, str>>, Vec<Cow<'static, str>>) =
+			core::iter::once(("Any".into(), "".into()))
+				.chain(
+					sources
+						.iter()
+						.map(|(name, id)| (name.clone().into(), id.clone().into())),
+				)
+				.unzip();
+
+		let (browse_options, browse_ids): (Vec<Cow<'static, str>>, Vec<Cow<'static, str>>) =
+			core::iter::once(("Library".into(), "".into()))
+				.chain(
+					sources
+						.into_iter()
+						.map(|(name, id)| (name.into(), id.into())),
+				)
+				.unzip();
+
+		Ok(vec![
+			SelectFilter {
+				id: SOURCE_FILTER_ID.into(),
+				title: Some("Source".into()),
+				options,
+				ids: Some(ids),
+				..Default::default()
+			}
+			.into(),
+			// searching an extension source ignores the library filters
+			SelectFilter {
+				id: BROWSE_FILTER_ID.into(),
+				title: Some("Browse".into()),
+				options: browse_options,
+				ids: Some(browse_ids),
+				..Default::default()
+			}
+			.into(),
+		])
+	}
+}
+
+impl ImageRequestProvider for Suwayomi {
+	fn get_image_request(&self, url: String, _context: Option<PageContext>) -> Result<Request> {
+		Request::get(url)?.authed()
+	}
+}
+
+impl BasicLoginHandler for Suwayomi {
+	fn handle_basic_login(&self, _key: String, username: String, password: String) -> Result<bool> {
+		match settings::auth_mode() {
+			AuthMode::None => bail!("Select an authentication mode before logging in"),
+			AuthMode::Basic => {
+				let base_url = settings::get_base_url()?;
+				let response = Request::post(format!("{base_url}/api/graphql"))?
+					.header("Content-Type", "application/json")
+					.header("Authorization", &auth::basic_header(&username, &password))
+					.body(graphql::GraphQLQuery::CATEGORIES.body(None).to_string())
+					.send()?;
+				Ok(response.status_code() == 200)
+			}
+			AuthMode::UiLogin => {
+				auth::login_with(&username, &password)?;
+				settings::set_just_logged_in();
+				Ok(true)
+			}
+		}
+	}
+}
+
+impl NotificationHandler for Suwayomi {
+	fn handle_notification(&self, notification: String) {
+		let patch = match notification.as_str() {
+			"login" => {
+				if settings::is_just_logged_in() {
+					settings::clear_just_logged_in();
+				} else {
+					// logged out
+					settings::clear_tokens();
+				}
+				return;
+			}
+			"addLastTitleToLibrary" => {
+				if let Some(mut manga) = settings::get_last_viewed_manga() {
+					let result = self.execute_query::<serde_json::Value>(
+						graphql::GraphQLQuery::UPDATE_MANGA,
+						Some(serde_json::json!({
+							"id": manga.id,
+							"patch": { "inLibrary": true }
+						})),
+					);
+					let status = match result {
+						Ok(_) => {
+							manga.in_library = true;
+							settings::set_last_viewed_manga(&manga);
+							format!("Added {} to the library.", manga.title)
+						}
+						Err(err) => {
+							format!("Failed to add {} to the library: {err:?}", manga.title)
+						}
+					};
+					settings::set_library_status(status);
+				}
+				return;
+			}
+			"markChapterRead" => serde_json::json!({ "isRead": true }),
+			"markChapterUnread" => serde_json::json!({ "isRead": false }),
+			"bookmarkChapter" => serde_json::json!({ "isBookmarked": true }),
+			"removeChapterBookmark" => serde_json::json!({ "isBookmarked": false }),
+			_ => return,
+		};
+		let Some(chapter) = settings::get_sync_chapter() else {
+			settings::set_progress_status("Pick a chapter to update first.".into());
+			return;
+		};
+		let status = match self.update_chapters(&[chapter.id], patch) {
+			Ok(states) => {
+				settings::update_recent_chapters(&states);
+				let chapter = settings::get_sync_chapter().unwrap_or(chapter);
+				format!("Updated {}", chapter.label())
+			}
+			Err(err) => format!("Failed to update {}: {err:?}", chapter.label()),
+		};
+		settings::set_progress_status(status);
+	}
+}
+
+impl DynamicSettings for Suwayomi {
+	fn get_dynamic_settings(&self) -> Result<Vec<Setting>> {
+		let chapters = settings::get_recent_chapters();
+		let (values, titles) = chapters
+			.iter()
+			.map(|chapter| (chapter.id.to_string().into(), chapter.label().into()))
+			.unzip();
+
+		let mut items: Vec<Setting> = vec![
+			SelectSetting {
+				key: "syncChapter".into(),
+				title: "Chapter".into(),
+				values,
+				titles: Some(titles),
+				refreshes: Some(vec!["settings".into()]),
+				..Default::default()
+			}
+			.into(),
+		];
+		for (key, title) in [
+			("markChapterRead", "Mark Read"),
+			("markChapterUnread", "Mark Unread"),
+			("bookmarkChapter", "Bookmark"),
+			("removeChapterBookmark", "Remove Bookmark"),
+		] {
+			items.push(
+				ButtonSetting {
+					key: key.into(),
+					title: title.into(),
+					notification: Some(key.into()),
+					requires: Some("syncChapter".into()),
+					refreshes: Some(vec!["settings".into()]),
+					..Default::default()
+				}
+				.into(),
+			);
+		}
+
+		let mut footer = String::from(
+			"Updates the picked chapter on the server. Chapters opened or downloaded in Aidoku are listed, newest first.",
+		);
+		if let Some(status) = settings::get_progress_status() {
+			footer = format!("{footer}\n{status}");
+		}
+
+		let mut groups: Vec<Setting> = vec![
+			GroupSetting {
+				key: "readingProgress".into(),
+				title: "Reading progress".into(),
+				items,
+				footer: Some(footer.into()),
+				..Default::default()
+			}
+			.into(),
+		];
+
+		if let Some(manga) = settings::get_last_viewed_manga() {
+			let mut items: Vec<Setting> = Vec::new();
+			let mut footer = if manga.in_library {
+				format!("{} is in the server library.", manga.title)
+			} else {
+				items.push(
+					ButtonSetting {
+						key: "addLastTitleToLibrary".into(),
+						title: format!("Add {} to Library", manga.title).into(),
+						notification: Some("addLastTitleToLibrary".into()),
+						refreshes: Some(vec!["settings".into()]),
+						..Default::default()
+					}
+					.into(),
+				);
+				String::from("The last title opened in Aidoku can be added to the server library.")
+			};
+			if let Some(status) = settings::get_library_status() {
+				footer = format!("{footer}\n{status}");
+			}
+			groups.push(
+				GroupSetting {
+					key: "library".into(),
+					title: "Library".into(),
+					items,
+					footer: Some(footer.into()),
+					..Default::default()
+				}
+				.into(),
+			);
+		}
+
+		Ok(groups)
 	}
 }
 
@@ -271,4 +658,14 @@ impl BaseUrlProvider for Suwayomi {
 	}
 }
 
-register_source!(Suwayomi, ListingProvider, BaseUrlProvider, DynamicListings);
+register_source!(
+	Suwayomi,
+	ListingProvider,
+	BaseUrlProvider,
+	DynamicListings,
+	DynamicFilters,
+	DynamicSettings,
+	ImageRequestProvider,
+	BasicLoginHandler,
+	NotificationHandler
+);
@@ -4,7 +4,7 @@ use aidoku::{
 };
 use alloc::string::ToString;
 use alloc::vec;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Deserialize)]
 pub struct GraphQLResponse<T> {
@@ -12,8 +12,17 @@ pub struct GraphQLResponse<T> {
 }
 
 #[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct Nodes<T> {
 	pub nodes: Vec<T>,
+	#[serde(default)]
+	pub page_info: Option<PageInfo>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PageInfo {
+	pub has_next_page: bool,
 }
 
 #[derive(Debug, Deserialize)]
@@ -26,7 +35,7 @@ pub struct MultipleMangas {
 pub struct MangaDto {
 	pub id: i32,
 	pub title: String,
-	pub thumbnail_url: String,
+	pub thumbnail_url: Option<String>,
 	pub author: Option<String>,
 	pub artist: Option<String>,
 	pub genre: Vec<String>,
@@ -51,7 +60,9 @@ impl MangaDto {
 		Manga {
 			key: self.id.to_string(),
 			title: self.title,
-			cover: Some(format!("{}{}", base_url, self.thumbnail_url)),
+			cover: self
+				.thumbnail_url
+				.map(|thumbnail| format!("{}{}", base_url, thumbnail)),
 			artists: self.artist.map(|a| vec![a]),
 			authors: self.author.map(|a| vec![a]),
 			url: Some(url),
@@ -86,6 +97,69 @@ pub struct Source {
 	pub display_name: String,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct MultipleSources {
+	pub sources: Nodes<SourceDto>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SourceDto {
+	pub id: String,
+	pub display_name: String,
+	pub lang: String,
+	#[serde(default)]
+	pub supports_latest: bool,
+}
+
+impl SourceDto {
+	// each extension source gets a popular listing, and a latest one if it supports it
+	pub fn into_listings(self) -> Vec<Listing> {
+		let name = if self.lang.is_empty() || self.lang == "localsourcelang" {
+			self.display_name
+		} else {
+			format!("{} ({})", self.display_name, self.lang)
+		};
+		let mut listings = vec![Listing {
+			id: format!("POPULAR:{}", self.id),
+			name: format!("{} - Popular", name),
+			kind: ListingKind::Default,
+		}];
+		if self.supports_latest {
+			listings.push(Listing {
+				id: format!("LATEST:{}", self.id),
+				name: format!("{} - Latest", name),
+				kind: ListingKind::Default,
+			});
+		}
+		listings
+	}
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FetchSourceMangaResponse {
+	pub fetch_source_manga: SourceMangaPage,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SourceMangaPage {
+	pub has_next_page: bool,
+	pub mangas: Vec<MangaDto>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FetchChaptersResponse {
+	pub fetch_chapters: FetchedChapters,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct FetchedChapters {
+	pub chapters: Vec<ChapterDto>,
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ChapterDto {
@@ -96,9 +170,24 @@ pub struct ChapterDto {
 	pub upload_date: String,
 	pub manga: SlimManga,
 	pub source_order: i32,
+	#[serde(default)]
+	pub is_read: bool,
+	#[serde(default)]
+	pub is_bookmarked: bool,
+	#[serde(default)]
+	pub last_page_read: i32,
 }
 
 impl ChapterDto {
+	pub fn state(&self) -> ChapterState {
+		ChapterState {
+			id: self.id,
+			is_read: self.is_read,
+			is_bookmarked: self.is_bookmarked,
+			last_page_read: self.last_page_read,
+		}
+	}
+
 	pub fn into_chapter(self, base_url: &str, manga_id: i32) -> Chapter {
 		let url = format!(
 			"{}/manga/{}/chapter/{}",
@@ -130,6 +219,89 @@ impl ChapterDto {
 	}
 }
 
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateChaptersResponse {
+	pub update_chapters: UpdatedChapters,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdatedChapters {
+	pub chapters: Vec<ChapterState>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChapterState {
+	pub id: i32,
+	pub is_read: bool,
+	pub is_bookmarked: bool,
+	pub last_page_read: i32,
+}
+
+// a chapter opened in aidoku, with its read state on the server when known
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct RecentChapter {
+	pub id: i32,
+	pub manga_title: String,
+	pub title: String,
+	pub state: Option<ChapterState>,
+}
+
+impl RecentChapter {
+	pub fn label(&self) -> String {
+		let title = format!("{} - {}", self.manga_title, self.title);
+		let Some(state) = &self.state else {
+			return title;
+		};
+		let mut labels: Vec<String> = Vec::new();
+		if state.is_read {
+			labels.push("read".into());
+		} else if state.last_page_read > 0 {
+			// the server stores the zero based index of the last page read
+			labels.push(format!("page {}", state.last_page_read + 1));
+		} else {
+			labels.push("unread".into());
+		}
+		if state.is_bookmarked {
+			labels.push("bookmarked".into());
+		}
+		format!("{title} ({})", labels.join(", "))
+	}
+}
+
+// the title whose details were last opened in aidoku
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ViewedManga {
+	pub id: i32,
+	pub title: String,
+	pub in_library: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct LoginResponse {
+	pub login: LoginTokens,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LoginTokens {
+	pub access_token: String,
+	pub refresh_token: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RefreshTokenResponse {
+	pub refresh_token: AccessToken,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessToken {
+	pub access_token: String,
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct FetchChapterPagesResponse {
@@ -147,8 +319,11 @@ pub struct MangaOnlyDescriptionResponse {
 }
 
 #[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct OnlyDescriptionManga {
-	pub description: String,
+	pub description: Option<String>,
+	#[serde(default)]
+	pub in_library: bool,
 }
 
 #[derive(Debug, Deserialize)]
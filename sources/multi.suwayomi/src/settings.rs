@@ -1,6 +1,33 @@
-use aidoku::{AidokuError, alloc::string::String, imports::defaults::defaults_get, prelude::bail};
+use crate::models::{ChapterState, RecentChapter, ViewedManga};
+use aidoku::{
+	AidokuError,
+	alloc::{string::String, vec::Vec},
+	imports::defaults::{DefaultValue, defaults_get, defaults_set},
+	prelude::bail,
+};
 
 const BASE_URL_KEY: &str = "baseUrl";
+const RECENT_CHAPTERS_KEY: &str = "recentChapters";
+const SYNC_CHAPTER_KEY: &str = "syncChapter";
+const PROGRESS_STATUS_KEY: &str = "progressStatus";
+const LAST_VIEWED_MANGA_KEY: &str = "lastViewedManga";
+const LIBRARY_STATUS_KEY: &str = "libraryStatus";
+const SOURCE_LISTINGS_KEY: &str = "sourceListings";
+const AUTH_MODE_KEY: &str = "authMode";
+const USERNAME_KEY: &str = "login.username";
+const PASSWORD_KEY: &str = "login.password";
+const ACCESS_TOKEN_KEY: &str = "accessToken";
+const REFRESH_TOKEN_KEY: &str = "refreshToken";
+const JUST_LOGGED_IN_KEY: &str = "justLoggedIn";
+
+const MAX_RECENT_CHAPTERS: usize = 10;
+
+#[derive(PartialEq)]
+pub enum AuthMode {
+	None,
+	Basic,
+	UiLogin,
+}
 
 pub fn get_base_url() -> Result<String, AidokuError> {
 	let base_url = defaults_get::<String>(BASE_URL_KEY);
@@ -9,3 +36,134 @@ pub fn get_base_url() -> Result<String, AidokuError> {
 		_ => bail!("Base Url not configured"),
 	}
 }
+
+// the chapters most recently opened in aidoku, newest first
+pub fn get_recent_chapters() -> Vec<RecentChapter> {
+	defaults_get::<String>(RECENT_CHAPTERS_KEY)
+		.and_then(|value| serde_json::from_str(&value).ok())
+		.unwrap_or_default()
+}
+
+fn set_recent_chapters(chapters: &[RecentChapter]) {
+	if let Ok(value) = serde_json::to_string(chapters) {
+		defaults_set(RECENT_CHAPTERS_KEY, DefaultValue::String(value));
+	}
+}
+
+pub fn add_recent_chapter(mut chapter: RecentChapter) {
+	let mut chapters = get_recent_chapters();
+	if let Some(index) = chapters.iter().position(|c| c.id == chapter.id) {
+		let previous = chapters.remove(index);
+		chapter.state = chapter.state.or(previous.state);
+	}
+	chapters.insert(0, chapter);
+	chapters.truncate(MAX_RECENT_CHAPTERS);
+	set_recent_chapters(&chapters);
+}
+
+// refreshes the server state of the recent chapters that are in `states`
+pub fn update_recent_chapters(states: &[ChapterState]) {
+	let mut chapters = get_recent_chapters();
+	let mut changed = false;
+	for chapter in &mut chapters {
+		if let Some(state) = states.iter().find(|state| state.id == chapter.id) {
+			chapter.state = Some(state.clone());
+			changed = true;
+		}
+	}
+	if changed {
+		set_recent_chapters(&chapters);
+	}
+}
+
+// the recent chapter picked in the settings to update on the server
+pub fn get_sync_chapter() -> Option<RecentChapter> {
+	let id = defaults_get::<String>(SYNC_CHAPTER_KEY)?
+		.parse::<i32>()
+		.ok()?;
+	get_recent_chapters().into_iter().find(|c| c.id == id)
+}
+
+// the result of the last reading progress update, shown in the settings
+pub fn get_progress_status() -> Option<String> {
+	defaults_get::<String>(PROGRESS_STATUS_KEY)
+}
+
+pub fn set_progress_status(status: String) {
+	defaults_set(PROGRESS_STATUS_KEY, DefaultValue::String(status));
+}
+
+// the title whose details were last opened, offered in the settings to add to the library
+pub fn get_last_viewed_manga() -> Option<ViewedManga> {
+	defaults_get::<String>(LAST_VIEWED_MANGA_KEY)
+		.and_then(|value| serde_json::from_str(&value).ok())
+}
+
+pub fn set_last_viewed_manga(manga: &ViewedManga) {
+	if get_last_viewed_manga().is_none_or(|previous| previous.id != manga.id) {
+		defaults_set(LIBRARY_STATUS_KEY, DefaultValue::Null);
+	}
+	if let Ok(value) = serde_json::to_string(manga) {
+		defaults_set(LAST_VIEWED_MANGA_KEY, DefaultValue::String(value));
+	}
+}
+
+// the result of the last "add to library" request, shown in the settings
+pub fn get_library_status() -> Option<String> {
+	defaults_get::<String>(LIBRARY_STATUS_KEY)
+}
+
+pub fn set_library_status(status: String) {
+	defaults_set(LIBRARY_STATUS_KEY, DefaultValue::String(status));
+}
+
+pub fn source_listings() -> bool {
+	defaults_get::<bool>(SOURCE_LISTINGS_KEY).unwrap_or(true)
+}
+
+pub fn auth_mode() -> AuthMode {
+	match defaults_get::<String>(AUTH_MODE_KEY).as_deref() {
+		Some("basic") => AuthMode::Basic,
+		Some("ui") => AuthMode::UiLogin,
+		_ => AuthMode::None,
+	}
+}
+
+pub fn get_credentials() -> Option<(String, String)> {
+	let username = defaults_get::<String>(USERNAME_KEY)?;
+	let password = defaults_get::<String>(PASSWORD_KEY)?;
+	Some((username, password))
+}
+
+pub fn get_access_token() -> Option<String> {
+	defaults_get::<String>(ACCESS_TOKEN_KEY).filter(|token| !token.is_empty())
+}
+
+pub fn get_refresh_token() -> Option<String> {
+	defaults_get::<String>(REFRESH_TOKEN_KEY).filter(|token| !token.is_empty())
+}
+
+pub fn set_access_token(token: &str) {
+	defaults_set(ACCESS_TOKEN_KEY, DefaultValue::String(token.into()));
+}
+
+pub fn set_refresh_token(token: &str) {
+	defaults_set(REFRESH_TOKEN_KEY, DefaultValue::String(token.into()));
+}
+
+pub fn clear_tokens() {
+	defaults_set(ACCESS_TOKEN_KEY, DefaultValue::Null);
+	defaults_set(REFRESH_TOKEN_KEY, DefaultValue::Null);
+}
+
+pub fn set_just_logged_in() {
+	defaults_set(JUST_LOGGED_IN_KEY, DefaultValue::Bool(true));
+}
+
+pub fn is_just_logged_in() -> bool {
+	defaults_get::<bool>(JUST_LOGGED_IN_KEY).unwrap_or(false)
+}
+
+pub fn clear_just_logged_in() {
+	defaults_set(JUST_LOGGED_IN_KEY, DefaultValue::Null);
+}
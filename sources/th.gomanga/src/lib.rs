@@ -14,6 +14,7 @@ impl Impl for GoManga {
 	fn params(&self) -> Params {
 		Params {
 			base_url: BASE_URL.into(),
+			fallback_filters: include_str!("../res/fallback_filters.json").into(),
 			..Default::default()
 		}
 	}
@@ -23,5 +24,7 @@ register_source!(
 	MangaThemesia<GoManga>,
 	Home,
 	ImageRequestProvider,
-	DeepLinkHandler
+	PageImageProcessor,
+	DeepLinkHandler,
+	DynamicFilters
 );
@@ -19,6 +19,7 @@ impl Impl for PrettyFrank {
 	fn params(&self) -> Params {
 		Params {
 			base_url: BASE_URL.into(),
+			fallback_filters: include_str!("../res/fallback_filters.json").into(),
 			..Default::default()
 		}
 	}
@@ -50,5 +51,7 @@ register_source!(
 	MangaThemesia<PrettyFrank>,
 	Home,
 	ImageRequestProvider,
-	DeepLinkHandler
+	PageImageProcessor,
+	DeepLinkHandler,
+	DynamicFilters
 );
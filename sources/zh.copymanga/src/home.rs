@@ -0,0 +1,62 @@
+use crate::{Copymanga, json::comic_list, net::Url};
+use aidoku::{
+	Home, HomeComponent, HomeComponentValue, HomeLayout, Listing, Result, alloc::Vec,
+	imports::net::Request,
+};
+
+impl Home for Copymanga {
+	fn get_home(&self) -> Result<HomeLayout> {
+		let sections = [
+			("recommended", "編輯推薦"),
+			("hot", "熱門"),
+			("newest", "新上架"),
+			("finished", "已完結"),
+		];
+
+		let urls = sections
+			.iter()
+			.map(|(id, _)| Url::from_listing(id, 1))
+			.collect::<Result<Vec<_>>>()?;
+		let requests = urls.iter().map(Url::request).collect::<Result<Vec<_>>>()?;
+		let responses = Request::send_all(requests);
+
+		let components = sections
+			.into_iter()
+			.zip(urls)
+			.zip(responses)
+			.filter_map(|(((id, name), url), response)| {
+				let entries = comic_list::manga_page_result(response.ok()?, url.is_wrapped_list())
+					.ok()?
+					.entries;
+				if entries.is_empty() {
+					return None;
+				}
+
+				let listing = Some(Listing {
+					id: id.into(),
+					name: name.into(),
+					..Default::default()
+				});
+				let value = if id == "recommended" {
+					HomeComponentValue::BigScroller {
+						entries,
+						auto_scroll_interval: Some(8.0),
+					}
+				} else {
+					HomeComponentValue::Scroller {
+						entries: entries.into_iter().map(Into::into).collect(),
+						listing,
+					}
+				};
+
+				Some(HomeComponent {
+					title: Some(name.into()),
+					subtitle: None,
+					value,
+				})
+			})
+			.collect();
+
+		Ok(HomeLayout { components })
+	}
+}
@@ -1,8 +1,8 @@
 use super::EncryptedJson as _;
-use crate::net::Url;
+use crate::{net::Url, settings};
 use aidoku::{
 	Chapter, HashMap, Result,
-	alloc::{String, Vec, borrow::ToOwned as _, string::ToString as _},
+	alloc::{String, Vec, borrow::ToOwned as _, format, string::ToString as _},
 	serde::Deserialize,
 };
 use chinese_number::{ChineseCountMethod, ChineseToNumber as _};
@@ -39,32 +39,51 @@ struct Results {
 
 impl From<Results> for Option<Vec<Chapter>> {
 	fn from(results: Results) -> Self {
+		settings::add_known_groups(
+			results
+				.groups
+				.iter()
+				.map(|(path_word, group)| (path_word.as_str(), group.name.as_str())),
+		);
+		let included_groups = settings::get_chapter_groups();
 		let mut groups = results
 			.groups
-			.into_values()
-			.map(|group| group.into_chapters(&results.build.path_word));
-		let mut chapters = groups.next()?;
-		chapters.reverse();
-
-		#[expect(clippy::arithmetic_side_effects)]
-		for mut group in groups {
-			let mut index = 0;
-
-			while let Some(chapter) = group.pop() {
-				while chapters.get(index).is_some_and(|sorted_chapter| {
-					sorted_chapter.date_uploaded.unwrap_or_default()
-						>= chapter.date_uploaded.unwrap_or_default()
-				}) {
-					index += 1;
-				}
+			.into_iter()
+			.filter(|(path_word, _)| included_groups.contains(path_word))
+			.map(|(path_word, group)| (GroupKind::new(&path_word), path_word, group))
+			.collect::<Vec<_>>();
+		// main chapters first, then volumes, then the remaining groups in a stable order
+		groups.sort_by(|(kind, path_word, _), (other_kind, other_path_word, _)| {
+			kind.cmp(other_kind)
+				.then_with(|| path_word.cmp(other_path_word))
+		});
+
+		let chapters = groups
+			.into_iter()
+			.flat_map(|(kind, _, group)| {
+				let mut chapters = group.into_chapters(&results.build.path_word, kind);
+				chapters.reverse();
+				chapters
+			})
+			.collect();
+		Some(chapters)
+	}
+}
 
-				chapters.insert(index, chapter);
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum GroupKind {
+	Default,
+	Tankobon,
+	Other,
+}
 
-				index += 1;
-			}
+impl GroupKind {
+	fn new(path_word: &str) -> Self {
+		match path_word {
+			"default" => Self::Default,
+			"tankobon" => Self::Tankobon,
+			_ => Self::Other,
 		}
-
-		Some(chapters)
 	}
 }
 
@@ -80,10 +99,10 @@ struct Group {
 }
 
 impl Group {
-	fn into_chapters(self, manga_key: &str) -> Vec<Chapter> {
+	fn into_chapters(self, manga_key: &str, kind: GroupKind) -> Vec<Chapter> {
 		self.chapters
 			.into_iter()
-			.map(|chapter_item| chapter_item.into_chapter(manga_key, &self.name))
+			.map(|chapter_item| chapter_item.into_chapter(manga_key, &self.name, kind))
 			.collect()
 	}
 }
@@ -96,10 +115,28 @@ struct ChapterItem {
 }
 
 impl ChapterItem {
-	fn into_chapter(self, manga_key: &str, group: &str) -> Chapter {
+	fn into_chapter(self, manga_key: &str, group: &str, kind: GroupKind) -> Chapter {
+		// the key doesn't depend on the group, so read state survives group changes
 		let key = self.id.to_string();
 
-		let (volume_number, chapter_number, title) = parse(self.r#type, self.name.trim());
+		let (mut volume_number, mut chapter_number, mut title) =
+			parse(self.r#type, self.name.trim());
+		match kind {
+			GroupKind::Default => (),
+			GroupKind::Tankobon => {
+				if volume_number.is_none() {
+					volume_number = chapter_number.take();
+				}
+			}
+			GroupKind::Other => {
+				// side stories and alternate editions keep their numbers, with the group name
+				// as a section in the title to tell them apart from the main chapters
+				title = Some(match title {
+					Some(title) => format!("{group}：{title}"),
+					None => group.into(),
+				});
+			}
+		}
 
 		let date_uploaded = self
 			.id
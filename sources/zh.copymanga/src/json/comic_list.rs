@@ -0,0 +1,60 @@
+use super::MangaItem;
+use aidoku::{
+	Manga, MangaPageResult, Result, alloc::Vec, imports::net::Response, serde::Deserialize,
+};
+
+pub fn manga_page_result(mut response: Response, is_wrapped: bool) -> Result<MangaPageResult> {
+	let manga_page_result = if is_wrapped {
+		response.get_json_owned::<Root<WrappedItem>>()?.into()
+	} else {
+		response.get_json_owned::<Root<MangaItem>>()?.into()
+	};
+	Ok(manga_page_result)
+}
+
+#[derive(Deserialize)]
+pub struct Root<T> {
+	results: Results<T>,
+}
+
+impl<T: Into<Manga>> From<Root<T>> for MangaPageResult {
+	fn from(root: Root<T>) -> Self {
+		root.results.into()
+	}
+}
+
+#[derive(Deserialize)]
+struct Results<T> {
+	list: Vec<T>,
+	total: u32,
+	limit: u32,
+	offset: u32,
+}
+
+impl<T: Into<Manga>> From<Results<T>> for MangaPageResult {
+	fn from(results: Results<T>) -> Self {
+		let entries = results.list.into_iter().map(Into::into).collect();
+
+		let has_next_page = results
+			.offset
+			.checked_add(results.limit)
+			.is_some_and(|current_total| current_total < results.total);
+
+		Self {
+			entries,
+			has_next_page,
+		}
+	}
+}
+
+// an entry of rankings, recommendations, new releases, the bookshelf or the browse history
+#[derive(Deserialize)]
+pub struct WrappedItem {
+	comic: MangaItem,
+}
+
+impl From<WrappedItem> for Manga {
+	fn from(item: WrappedItem) -> Self {
+		item.comic.into()
+	}
+}
@@ -0,0 +1,17 @@
+use aidoku::{alloc::String, serde::Deserialize};
+
+#[derive(Deserialize)]
+pub struct Root {
+	results: Option<Results>,
+}
+
+impl Root {
+	pub fn token(self) -> Option<String> {
+		self.results?.token
+	}
+}
+
+#[derive(Deserialize)]
+struct Results {
+	token: Option<String>,
+}
@@ -1,4 +1,6 @@
 pub mod chapter_list;
+pub mod comic_list;
+pub mod login;
 pub mod page_list;
 pub mod search;
 
@@ -20,6 +22,7 @@ pub struct MangaItem {
 	name: String,
 	cover: String,
 	status: Option<u8>,
+	#[serde(default)]
 	author: Vec<Author>,
 }
 
@@ -1,18 +1,22 @@
 #![no_std]
 
+mod home;
 mod html;
 mod json;
 mod net;
+mod settings;
 
 use aidoku::{
-	Chapter, DeepLinkHandler, DeepLinkResult, DynamicFilters, Filter, FilterValue, Manga,
-	MangaPageResult, Page, Result, Source,
+	BasicLoginHandler, Chapter, DeepLinkHandler, DeepLinkResult, DynamicFilters, DynamicListings,
+	DynamicSettings, Filter, FilterValue, GroupSetting, Listing, ListingProvider, Manga,
+	MangaPageResult, MultiSelectSetting, NotificationHandler, Page, Result, Setting, Source,
 	alloc::{String, Vec},
+	bail,
 	imports::std::send_partial_result,
 	register_source,
 };
 use html::{ChapterPage as _, FiltersPage as _, GenresPage as _, KeyPage as _, MangaPage as _};
-use json::{chapter_list, search};
+use json::{chapter_list, comic_list, search};
 use net::Url;
 
 struct Copymanga;
@@ -73,6 +77,59 @@ impl Source for Copymanga {
 	}
 }
 
+impl ListingProvider for Copymanga {
+	fn get_manga_list(&self, listing: Listing, page: i32) -> Result<MangaPageResult> {
+		let url = Url::from_listing(&listing.id, page)?;
+		let response = url.request()?.send()?;
+		comic_list::manga_page_result(response, url.is_wrapped_list())
+	}
+}
+
+impl DynamicListings for Copymanga {
+	fn get_dynamic_listings(&self) -> Result<Vec<Listing>> {
+		if !settings::is_logged_in() {
+			return Ok(Vec::new());
+		}
+
+		let listings = [("bookshelf", "書架"), ("history", "瀏覽歷史")]
+			.into_iter()
+			.map(|(id, name)| Listing {
+				id: id.into(),
+				name: name.into(),
+				..Default::default()
+			})
+			.collect();
+		Ok(listings)
+	}
+}
+
+impl BasicLoginHandler for Copymanga {
+	fn handle_basic_login(&self, key: String, username: String, password: String) -> Result<bool> {
+		if key != "login" {
+			bail!("Invalid login key: `{key}`");
+		}
+
+		let Ok(token) = net::login(&username, &password) else {
+			return Ok(false);
+		};
+		settings::set_token(&token);
+		settings::set_just_logged_in();
+		Ok(true)
+	}
+}
+
+impl NotificationHandler for Copymanga {
+	fn handle_notification(&self, notification: String) {
+		if notification == "login" {
+			if settings::is_just_logged_in() {
+				settings::clear_just_logged_in();
+			} else {
+				settings::clear_token();
+			}
+		}
+	}
+}
+
 impl DeepLinkHandler for Copymanga {
 	fn handle_deep_link(&self, url: String) -> Result<Option<DeepLinkResult>> {
 		let mut splits = url.split('/').skip(3);
@@ -112,4 +169,45 @@ impl DynamicFilters for Copymanga {
 	}
 }
 
-register_source!(Copymanga, DeepLinkHandler, DynamicFilters);
+impl DynamicSettings for Copymanga {
+	fn get_dynamic_settings(&self) -> Result<Vec<Setting>> {
+		let (values, titles): (Vec<_>, Vec<_>) = settings::get_known_groups()
+			.into_iter()
+			.map(|(path_word, name)| (path_word.into(), name.into()))
+			.unzip();
+		let groups = MultiSelectSetting {
+			key: "chapter.groups".into(),
+			title: "顯示的分組".into(),
+			default: Some(values.clone()),
+			values,
+			titles: Some(titles),
+			refreshes: Some(["content".into()].into()),
+			..Default::default()
+		}
+		.into();
+		let chapter = GroupSetting {
+			key: "chapter".into(),
+			title: "章節".into(),
+			items: [groups].into(),
+			footer: Some(
+				"單行本以卷顯示，其他分組（番外篇、全彩版等）以分組名稱作為標題；打開漫畫後會列出新的分組"
+					.into(),
+			),
+			..Default::default()
+		}
+		.into();
+		Ok([chapter].into())
+	}
+}
+
+register_source!(
+	Copymanga,
+	Home,
+	ListingProvider,
+	DynamicListings,
+	DeepLinkHandler,
+	DynamicFilters,
+	BasicLoginHandler,
+	NotificationHandler,
+	DynamicSettings
+);
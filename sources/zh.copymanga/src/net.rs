@@ -1,11 +1,12 @@
-use crate::html::GenresPage as _;
+use crate::{html::GenresPage as _, json::login, settings};
 use aidoku::{
 	FilterValue, Result,
 	alloc::{String, format, string::ToString as _},
 	bail, error,
 	helpers::uri::QueryParameters,
-	imports::{defaults::defaults_get, net::Request},
+	imports::{defaults::defaults_get, net::Request, std::current_date},
 };
+use base64::{Engine as _, engine::general_purpose::STANDARD};
 use core::fmt::{Display, Formatter, Result as FmtResult};
 use strum::{AsRefStr, Display, EnumIs, FromRepr};
 
@@ -25,6 +26,28 @@ pub enum Url<'a> {
 	ChapterList { manga_key: &'a str },
 	#[strum(to_string = "/comic/{manga_key}/chapter/{key}")]
 	Chapter { manga_key: &'a str, key: &'a str },
+	#[strum(to_string = "/api/v3/recs?pos=3200102&{0}")]
+	Recommended(PageQuery),
+	#[strum(to_string = "/api/v3/comics?ordering=-popular&{0}")]
+	Hot(PageQuery),
+	#[strum(to_string = "/api/v3/update/newest?{0}")]
+	NewReleases(PageQuery),
+	#[strum(to_string = "/api/v3/comics?top=finish&ordering=-datetime_updated&{0}")]
+	Finished(PageQuery),
+	#[strum(to_string = "/api/v3/ranks?date_type={date}&audience_type={audience}&{query}")]
+	Ranking {
+		date: &'a str,
+		audience: &'a str,
+		query: PageQuery,
+	},
+	#[strum(
+		to_string = "/api/v3/member/collect/comics?free_type=1&ordering=-datetime_modifier&{0}"
+	)]
+	Bookshelf(PageQuery),
+	#[strum(to_string = "/api/v3/member/browse/comics?free_type=1&{0}")]
+	BrowseHistory(PageQuery),
+	#[strum(to_string = "/api/v3/login")]
+	Login,
 }
 
 impl Url<'_> {
@@ -36,14 +59,25 @@ impl Url<'_> {
 
 	pub fn request(&self) -> Result<Request> {
 		let url = self.to_string()?;
-		let request = Request::get(url)?.header(
+		let mut request = Request::get(url)?.header(
 			"User-Agent",
 			"Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) \
 			 AppleWebKit/605.1.15 (KHTML, like Gecko) Version/26.0.1 Safari/605.1.15",
 		);
+
+		if self.is_bookshelf() || self.is_browse_history() {
+			let token = settings::get_token().ok_or_else(|| error!("請先登入"))?;
+			request.set_header("Authorization", &format!("Token {token}"));
+		}
+
 		Ok(request)
 	}
 
+	// api lists other than the comic lists wrap each entry in a `comic` field
+	pub const fn is_wrapped_list(&self) -> bool {
+		!matches!(*self, Self::Hot(_) | Self::Finished(_))
+	}
+
 	pub fn from_query_or_filters(
 		query: Option<&str>,
 		page: i32,
@@ -127,6 +161,34 @@ impl Url<'_> {
 }
 
 impl<'a> Url<'a> {
+	pub fn from_listing(listing_id: &'a str, page: i32) -> Result<Self> {
+		let query = PageQuery::new(page, 21);
+		let url = match listing_id {
+			"recommended" => Self::Recommended(query),
+			"hot" => Self::Hot(query),
+			"newest" => Self::NewReleases(query),
+			"finished" => Self::Finished(query),
+			"bookshelf" => Self::Bookshelf(query),
+			"history" => Self::BrowseHistory(query),
+			_ => {
+				// ex: day-male, total-female
+				let (date, audience) = listing_id
+					.split_once('-')
+					.filter(|(date, audience)| {
+						matches!(*date, "day" | "week" | "month" | "total")
+							&& matches!(*audience, "male" | "female")
+					})
+					.ok_or_else(|| error!("Invalid listing ID: `{listing_id}`"))?;
+				Self::Ranking {
+					date,
+					audience,
+					query,
+				}
+			}
+		};
+		Ok(url)
+	}
+
 	pub const fn manga(key: &'a str) -> Self {
 		Self::Manga { key }
 	}
@@ -140,6 +202,49 @@ impl<'a> Url<'a> {
 	}
 }
 
+pub fn login(username: &str, password: &str) -> Result<String> {
+	// the password is sent as base64("{password}-{salt}")
+	let salt = current_date().rem_euclid(9000).saturating_add(1000);
+	let encoded_password = STANDARD.encode(format!("{password}-{salt}"));
+
+	let mut body = QueryParameters::new();
+	body.push("username", Some(username));
+	body.push("password", Some(&encoded_password));
+	body.push_encoded("salt", Some(&salt.to_string()));
+	body.push_encoded("source", Some("freeSite"));
+	body.push_encoded("platform", Some("3"));
+
+	let url = Url::Login.to_string()?;
+	let token = Request::post(url)?
+		.header("Content-Type", "application/x-www-form-urlencoded")
+		.body(body.to_string())
+		.json_owned::<login::Root>()?
+		.token()
+		.ok_or_else(|| error!("登入失敗"))?;
+	Ok(token)
+}
+
+pub struct PageQuery(QueryParameters);
+
+impl PageQuery {
+	fn new(page: i32, limit: i32) -> Self {
+		let mut query = QueryParameters::new();
+
+		let offset = Offset::new(page, limit).to_string();
+		query.push_encoded("offset", Some(&offset));
+		query.push_encoded("limit", Some(&limit.to_string()));
+		query.push_encoded("platform", Some("3"));
+
+		Self(query)
+	}
+}
+
+impl Display for PageQuery {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		write!(f, "{}", self.0)
+	}
+}
+
 pub struct FiltersQuery(QueryParameters);
 
 impl FiltersQuery {
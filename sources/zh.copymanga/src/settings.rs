@@ -0,0 +1,93 @@
+use aidoku::{
+	alloc::{String, Vec},
+	imports::defaults::{DefaultValue, defaults_get, defaults_set},
+};
+
+const CHAPTER_GROUPS_KEY: &str = "chapter.groups";
+const KNOWN_GROUPS_KEY: &str = "chapter.knownGroups";
+const KNOWN_GROUP_NAMES_KEY: &str = "chapter.knownGroupNames";
+const TOKEN_KEY: &str = "login.token";
+const JUST_LOGGED_IN_KEY: &str = "login.justLoggedIn";
+
+// the path words of the groups to include, all known groups unless changed
+pub fn get_chapter_groups() -> Vec<String> {
+	defaults_get::<Vec<String>>(CHAPTER_GROUPS_KEY).unwrap_or_else(|| {
+		get_known_groups()
+			.into_iter()
+			.map(|(path_word, _)| path_word)
+			.collect()
+	})
+}
+
+// the path words and names of the groups seen so far, for the group setting
+pub fn get_known_groups() -> Vec<(String, String)> {
+	let path_words = defaults_get::<Vec<String>>(KNOWN_GROUPS_KEY);
+	let names = defaults_get::<Vec<String>>(KNOWN_GROUP_NAMES_KEY);
+	match (path_words, names) {
+		(Some(path_words), Some(names)) => path_words.into_iter().zip(names).collect(),
+		_ => [
+			("default".into(), "默認".into()),
+			("tankobon".into(), "單行本".into()),
+		]
+		.into(),
+	}
+}
+
+// remembers new groups, which are also included if the included groups were changed
+pub fn add_known_groups<'a>(groups: impl Iterator<Item = (&'a str, &'a str)>) {
+	let mut known_groups = get_known_groups();
+	let mut chapter_groups = defaults_get::<Vec<String>>(CHAPTER_GROUPS_KEY);
+	let mut changed = false;
+	for (path_word, name) in groups {
+		if known_groups.iter().any(|(known, _)| known == path_word) {
+			continue;
+		}
+		known_groups.push((path_word.into(), name.into()));
+		if let Some(chapter_groups) = &mut chapter_groups {
+			chapter_groups.push(path_word.into());
+		}
+		changed = true;
+	}
+	if !changed {
+		return;
+	}
+
+	let (path_words, names) = known_groups.into_iter().unzip();
+	defaults_set(KNOWN_GROUPS_KEY, DefaultValue::StringArray(path_words));
+	defaults_set(KNOWN_GROUP_NAMES_KEY, DefaultValue::StringArray(names));
+	if let Some(chapter_groups) = chapter_groups {
+		defaults_set(
+			CHAPTER_GROUPS_KEY,
+			DefaultValue::StringArray(chapter_groups),
+		);
+	}
+}
+
+pub fn get_token() -> Option<String> {
+	defaults_get::<String>(TOKEN_KEY).filter(|token| !token.is_empty())
+}
+
+pub fn set_token(token: &str) {
+	defaults_set(TOKEN_KEY, DefaultValue::String(token.into()));
+}
+
+pub fn clear_token() {
+	defaults_set(TOKEN_KEY, DefaultValue::Null);
+}
+
+pub fn is_logged_in() -> bool {
+	get_token().is_some()
+}
+
+// the login notification is also sent on logout, so a flag tells the two apart
+pub fn set_just_logged_in() {
+	defaults_set(JUST_LOGGED_IN_KEY, DefaultValue::Bool(true));
+}
+
+pub fn is_just_logged_in() -> bool {
+	defaults_get::<bool>(JUST_LOGGED_IN_KEY).unwrap_or(false)
+}
+
+pub fn clear_just_logged_in() {
+	defaults_set(JUST_LOGGED_IN_KEY, DefaultValue::Null);
+}
@@ -63,6 +63,9 @@ impl Home for Picacomic {
 			],
 		}));
 
+		// punch in after the layout so the home page isn't held up by it
+		net::try_punch_in();
+
 		let build_requests = || -> Result<[Request; 8]> {
 			let requests = [
 				// daily ranking
@@ -27,6 +27,19 @@ pub struct ComicItem {
 	#[serde(rename = "chineseTeam")]
 	pub chinese_team: Option<String>,
 	pub created_at: Option<String>,
+	// only present in the comic details
+	#[serde(rename = "_creator")]
+	pub creator: Option<Creator>,
+	#[serde(rename = "isFavourite", default)]
+	pub is_favourite: bool,
+	#[serde(rename = "isLiked", default)]
+	pub is_liked: bool,
+}
+
+#[derive(Deserialize)]
+pub struct Creator {
+	pub _id: String,
+	pub name: String,
 }
 
 #[derive(Deserialize)]
@@ -126,6 +139,16 @@ pub struct PagesData {
 	pub limit: i32,
 }
 
+#[derive(Deserialize)]
+pub struct ActionResponse {
+	pub data: ActionData,
+}
+
+#[derive(Deserialize)]
+pub struct ActionData {
+	pub action: String,
+}
+
 impl From<ComicItem> for Manga {
 	fn from(item: ComicItem) -> Self {
 		let cover = format!("{}/static/{}", item.thumb.file_server, item.thumb.path);
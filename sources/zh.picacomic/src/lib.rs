@@ -1,9 +1,10 @@
 #![no_std]
 
 use aidoku::{
-	BasicLoginHandler, Chapter, DeepLinkHandler, DeepLinkResult, FilterValue, Listing,
-	ListingProvider, Manga, MangaPageResult, Page, Result, Source,
-	alloc::{String, Vec},
+	BasicLoginHandler, ButtonSetting, Chapter, DeepLinkHandler, DeepLinkResult, DynamicSettings,
+	FilterValue, GroupSetting, Listing, ListingProvider, Manga, MangaPageResult,
+	NotificationHandler, Page, Result, Setting, Source,
+	alloc::{String, Vec, vec},
 	prelude::*,
 };
 
@@ -27,6 +28,10 @@ impl Source for Picacomic {
 		filters: Vec<FilterValue>,
 	) -> Result<MangaPageResult> {
 		let url = net::Url::from_query_or_filters(query.as_deref(), page, &filters)?;
+		if let net::Url::Recommendation { .. } = url {
+			let response: json::RankResponse = net::request_json(url)?;
+			return Ok(response.data.into());
+		}
 		let response: json::ExploreResponse = net::request_json(url)?;
 		Ok(response.data.into())
 	}
@@ -42,7 +47,29 @@ impl Source for Picacomic {
 				id: manga.key.clone(),
 			};
 			let response: json::ComicResponse = net::request_json(url)?;
-			let comic: Manga = response.data.comic.into();
+			let comic = response.data.comic;
+			// remembered for the favourite and like buttons in the settings
+			settings::set_last_comic(&comic._id, &comic.title, comic.is_favourite, comic.is_liked);
+
+			// links to the uploader's comics and the recommendations, opened through the
+			// deep link handler
+			let mut links = Vec::new();
+			if let Some(creator) = &comic.creator {
+				links.push(format!(
+					"[上传者：{}](https://picaapi.picacomic.com/comics?ca={})",
+					creator.name, creator._id
+				));
+			}
+			links.push(format!(
+				"[看了这本子的人也在看](https://manhuabika.com/pcomicview/recommendation/?cid={})",
+				comic._id
+			));
+
+			let mut comic: Manga = comic.into();
+			comic.description = Some(match comic.description {
+				Some(description) => format!("{description}  \n{}", links.join("  \n")),
+				None => links.join("  \n"),
+			});
 			manga = Manga {
 				chapters: manga.chapters,
 				..comic
@@ -104,6 +131,32 @@ impl Source for Picacomic {
 	}
 }
 
+// comic ids are 24 character hex strings, either bare or inside a share link
+fn parse_comic_id(text: &str) -> Option<String> {
+	let is_id = |s: &str| s.len() == 24 && s.chars().all(|c| c.is_ascii_hexdigit());
+	let text = text.trim();
+	if is_id(text) {
+		return Some(text.into());
+	}
+	// ex: https://manhuabika.com/pcomicview/?cid=5821859b5f6b9a4f93dbf6e9
+	if let Some((_, query)) = text.split_once('?') {
+		let id = query
+			.split('&')
+			.find_map(|param| param.strip_prefix("cid="))
+			.map(|id| id.split('#').next().unwrap_or_default());
+		if let Some(id) = id.filter(|id| is_id(id)) {
+			return Some(id.into());
+		}
+	}
+	// ex: https://picaapi.picacomic.com/comics/5821859b5f6b9a4f93dbf6e9
+	text.split(['?', '#'])
+		.next()
+		.unwrap_or_default()
+		.split('/')
+		.find(|segment| is_id(segment))
+		.map(Into::into)
+}
+
 fn get_chapter_list_by_page(id: String, page: i32) -> Result<Vec<aidoku::Chapter>> {
 	let url = net::Url::ChapterList { id, page };
 	let response: json::ChapterResponse = net::request_json(url)?;
@@ -150,6 +203,22 @@ impl ListingProvider for Picacomic {
 			"djkz" => category = Some(String::from("大家都在看")),
 			"gfdjkz" => category = Some(String::from("官方都在看")),
 			"update" => return self.get_search_manga_list(None, page, Vec::new()),
+			id if id.starts_with("creator:") => {
+				let url = net::Url::Creator {
+					creator: id["creator:".len()..].into(),
+					sort,
+					page,
+				};
+				let response: json::ExploreResponse = net::request_json(url)?;
+				return Ok(response.data.into());
+			}
+			id if id.starts_with("recommend:") => {
+				let url = net::Url::Recommendation {
+					id: id["recommend:".len()..].into(),
+				};
+				let response: json::RankResponse = net::request_json(url)?;
+				return Ok(response.data.into());
+			}
 			_ => bail!("Invalid listing"),
 		};
 
@@ -180,8 +249,135 @@ impl ListingProvider for Picacomic {
 }
 
 impl DeepLinkHandler for Picacomic {
-	fn handle_deep_link(&self, _url: String) -> Result<Option<DeepLinkResult>> {
-		Ok(None)
+	fn handle_deep_link(&self, url: String) -> Result<Option<DeepLinkResult>> {
+		// ex: https://picaapi.picacomic.com/comics?ca=58f649a80a48790773c7017c
+		let creator = url
+			.split_once('?')
+			.and_then(|(_, query)| query.split('&').find_map(|param| param.strip_prefix("ca=")));
+		if let Some(creator) = creator {
+			return Ok(Some(DeepLinkResult::Listing(Listing {
+				id: format!("creator:{creator}"),
+				name: String::from("上传者作品"),
+				..Default::default()
+			})));
+		}
+
+		let Some(id) = parse_comic_id(&url) else {
+			return Ok(None);
+		};
+		if url.contains("/recommendation") {
+			return Ok(Some(DeepLinkResult::Listing(Listing {
+				id: format!("recommend:{id}"),
+				name: String::from("看了这本子的人也在看"),
+				..Default::default()
+			})));
+		}
+		Ok(Some(DeepLinkResult::Manga { key: id }))
+	}
+}
+
+// favourites or likes the last opened comic, checking its current state first since both
+// endpoints toggle it
+fn set_comic_state(id: &str, favourite: Option<bool>, like: Option<bool>) -> Result<(bool, bool)> {
+	let url = net::Url::Manga { id: id.into() };
+	let comic = net::request_json::<json::ComicResponse>(url)?.data.comic;
+	let (mut is_favourite, mut is_liked) = (comic.is_favourite, comic.is_liked);
+
+	// the endpoints report the new state, e.g. "favourite"/"un_favourite" or "like"/"unlike"
+	if let Some(favourite) = favourite
+		&& favourite != is_favourite
+	{
+		let url = net::Url::ToggleFavourite { id: id.into() };
+		let response: json::ActionResponse = net::request_json(url)?;
+		is_favourite = response.data.action == "favourite";
+	}
+	if let Some(like) = like
+		&& like != is_liked
+	{
+		let url = net::Url::Like { id: id.into() };
+		let response: json::ActionResponse = net::request_json(url)?;
+		is_liked = response.data.action == "like";
+	}
+	Ok((is_favourite, is_liked))
+}
+
+impl NotificationHandler for Picacomic {
+	fn handle_notification(&self, notification: String) {
+		let (favourite, like, action) = match notification.as_str() {
+			"addFavourite" => (Some(true), None, "收藏"),
+			"removeFavourite" => (Some(false), None, "取消收藏"),
+			"likeComic" => (None, Some(true), "点赞"),
+			"unlikeComic" => (None, Some(false), "取消点赞"),
+			_ => return,
+		};
+		let Some((id, title)) = settings::get_last_comic() else {
+			return;
+		};
+		let status = match set_comic_state(&id, favourite, like) {
+			Ok((is_favourite, is_liked)) => {
+				settings::set_last_comic_state(is_favourite, is_liked);
+				format!("已{action}《{title}》")
+			}
+			Err(err) => format!("{action}《{title}》失败：{err:?}"),
+		};
+		settings::set_action_status(status);
+	}
+}
+
+impl DynamicSettings for Picacomic {
+	fn get_dynamic_settings(&self) -> Result<Vec<Setting>> {
+		let Some((_, title)) = settings::get_last_comic() else {
+			return Ok(Vec::new());
+		};
+		let (is_favourite, is_liked) = settings::get_last_comic_state();
+
+		let button = |key: &str, title: &str| -> Setting {
+			ButtonSetting {
+				key: String::from(key).into(),
+				title: String::from(title).into(),
+				notification: Some(String::from(key).into()),
+				requires: Some("login".into()),
+				refreshes: Some(vec!["settings".into(), "listings".into()]),
+				..Default::default()
+			}
+			.into()
+		};
+		let items = vec![
+			if is_favourite {
+				button("removeFavourite", "取消收藏")
+			} else {
+				button("addFavourite", "收藏")
+			},
+			if is_liked {
+				button("unlikeComic", "取消点赞")
+			} else {
+				button("likeComic", "点赞")
+			},
+		];
+
+		let mut footer = format!(
+			"最近打开的本子《{title}》：{}，{}",
+			if is_favourite {
+				"已收藏"
+			} else {
+				"未收藏"
+			},
+			if is_liked { "已点赞" } else { "未点赞" },
+		);
+		if let Some(status) = settings::get_action_status() {
+			footer = format!("{footer}\n{status}");
+		}
+
+		Ok(vec![
+			GroupSetting {
+				key: "comicActions".into(),
+				title: "收藏与点赞".into(),
+				items,
+				footer: Some(footer.into()),
+				..Default::default()
+			}
+			.into(),
+		])
 	}
 }
 
@@ -202,5 +398,47 @@ register_source!(
 	Home,
 	ListingProvider,
 	DeepLinkHandler,
-	BasicLoginHandler
+	BasicLoginHandler,
+	NotificationHandler,
+	DynamicSettings
 );
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use aidoku_test::aidoku_test;
+
+	#[aidoku_test]
+	fn test_parse_comic_id() {
+		let id = Some(String::from("5821859b5f6b9a4f93dbf6e9"));
+		assert_eq!(parse_comic_id("5821859b5f6b9a4f93dbf6e9"), id);
+		assert_eq!(parse_comic_id(" 5821859b5f6b9a4f93dbf6e9\n"), id);
+		assert_eq!(
+			parse_comic_id("https://manhuabika.com/pcomicview/?cid=5821859b5f6b9a4f93dbf6e9"),
+			id
+		);
+		assert_eq!(
+			parse_comic_id(
+				"https://manhuabika.com/pcomicview/recommendation/?cid=5821859b5f6b9a4f93dbf6e9#top"
+			),
+			id
+		);
+		assert_eq!(
+			parse_comic_id("https://picaapi.picacomic.com/comics/5821859b5f6b9a4f93dbf6e9"),
+			id
+		);
+		assert_eq!(
+			parse_comic_id(
+				"https://picaapi.picacomic.com/comics/5821859b5f6b9a4f93dbf6e9/recommendation"
+			),
+			id
+		);
+		assert_eq!(parse_comic_id("5821859b5f6b9a4f93dbf6e"), None);
+		assert_eq!(parse_comic_id("5821859b5f6b9a4f93dbf6eg"), None);
+		assert_eq!(
+			parse_comic_id("https://manhuabika.com/pcomicview/?cid=abc"),
+			None
+		);
+		assert_eq!(parse_comic_id(""), None);
+	}
+}
@@ -26,8 +26,15 @@ pub fn gen_nonce() -> String {
 	format!("{:x}", compute(gen_time()))
 }
 
-pub fn gen_signature(url: &str, time: &str, nonce: &str, method: &str) -> Result<String> {
+pub fn gen_signature(url: &str, time: &str, nonce: &str, method: HttpMethod) -> Result<String> {
 	let url = url.trim_start_matches(&format!("{}/", API_URL));
+	let method = match method {
+		HttpMethod::Get => "GET",
+		HttpMethod::Post => "POST",
+		HttpMethod::Put => "PUT",
+		HttpMethod::Delete => "DELETE",
+		_ => "GET",
+	};
 	let text = format!("{}{}{}{}{}", url, time, nonce, method, API_KEY).to_ascii_lowercase();
 	crypto::encrypt(text.as_bytes(), KEY)
 }
@@ -49,6 +56,14 @@ pub enum Url {
 		sort: String,
 		page: i32,
 	},
+	Creator {
+		creator: String,
+		sort: String,
+		page: i32,
+	},
+	Recommendation {
+		id: String,
+	},
 	Rank {
 		time: String,
 	},
@@ -69,6 +84,13 @@ pub enum Url {
 		chapter_id: String,
 		page: i32,
 	},
+	ToggleFavourite {
+		id: String,
+	},
+	Like {
+		id: String,
+	},
+	PunchIn,
 }
 
 impl Url {
@@ -79,34 +101,16 @@ impl Url {
 	) -> Result<Self> {
 		let mut category = String::new();
 		let mut sort = String::from("dd");
-
-		if let Some(q) = query {
-			return Ok(Self::Search {
-				query: q.to_string(),
-				sort,
-				page,
-			});
-		}
+		// the text filter (or genre) to search by, applied after the sort is read
+		let mut text: Option<(&str, &str)> = query.map(|q| ("", q));
 
 		for filter in filters {
 			match filter {
-				FilterValue::Text { id, value } => match id.as_str() {
-					"author" => {
-						return Ok(Self::Author {
-							author: value.to_string(),
-							sort,
-							page,
-						});
-					}
-					_ => {
-						// Title search
-						return Ok(Self::Search {
-							query: value.to_string(),
-							sort,
-							page,
-						});
+				FilterValue::Text { id, value } => {
+					if text.is_none() {
+						text = Some((id.as_str(), value.as_str()));
 					}
-				},
+				}
 				FilterValue::Select { id, value } => match id.as_str() {
 					"类别" => {
 						category = if value.as_str() == "全部" {
@@ -116,11 +120,9 @@ impl Url {
 						};
 					}
 					"genre" => {
-						return Ok(Self::Search {
-							query: value.to_string(),
-							sort,
-							page,
-						});
+						if text.is_none() {
+							text = Some(("", value.as_str()));
+						}
 					}
 					_ => {}
 				},
@@ -136,17 +138,41 @@ impl Url {
 			}
 		}
 
-		Ok(Self::Explore {
-			category,
-			sort,
-			page,
+		Ok(match text {
+			Some(("author", value)) => Self::Author {
+				author: value.to_string(),
+				sort,
+				page,
+			},
+			Some(("uploader", value)) => Self::Creator {
+				creator: value.trim().to_string(),
+				sort,
+				page,
+			},
+			Some(("recommend", value)) => {
+				let id = crate::parse_comic_id(value).ok_or(error!("Invalid comic id"))?;
+				Self::Recommendation { id }
+			}
+			// title search
+			Some((_, value)) => Self::Search {
+				query: value.to_string(),
+				sort,
+				page,
+			},
+			None => Self::Explore {
+				category,
+				sort,
+				page,
+			},
 		})
 	}
 
 	pub fn request(&self) -> Result<Request> {
 		let url = self.to_string();
 		let method = match self {
-			Url::Search { .. } => HttpMethod::Post,
+			Url::Search { .. } | Url::ToggleFavourite { .. } | Url::Like { .. } | Url::PunchIn => {
+				HttpMethod::Post
+			}
 			_ => HttpMethod::Get,
 		};
 		let body = match self {
@@ -206,6 +232,23 @@ impl Display for Url {
 					sort
 				)
 			}
+			Url::Creator {
+				creator,
+				sort,
+				page,
+			} => {
+				write!(
+					f,
+					"{}/comics?page={}&ca={}&s={}",
+					API_URL,
+					page,
+					encode_uri(creator),
+					sort
+				)
+			}
+			Url::Recommendation { id } => {
+				write!(f, "{}/comics/{}/recommendation", API_URL, id)
+			}
 			Url::Rank { time } => {
 				write!(f, "{}", gen_rank_url(time.to_string()))
 			}
@@ -232,6 +275,15 @@ impl Display for Url {
 					API_URL, manga_id, chapter_id, page
 				)
 			}
+			Url::ToggleFavourite { id } => {
+				write!(f, "{}/comics/{}/favourite", API_URL, id)
+			}
+			Url::Like { id } => {
+				write!(f, "{}/comics/{}/like", API_URL, id)
+			}
+			Url::PunchIn => {
+				write!(f, "{}/users/punch-in", API_URL)
+			}
 		}
 	}
 }
@@ -294,16 +346,7 @@ pub fn create_request(url: String, method: HttpMethod, body: Option<String>) ->
 
 	let time = gen_time();
 	let nonce = gen_nonce();
-	let signature = gen_signature(
-		&url,
-		&time,
-		&nonce,
-		match method {
-			HttpMethod::Get => "GET",
-			HttpMethod::Post => "POST",
-			_ => "GET",
-		},
-	)?;
+	let signature = gen_signature(&url, &time, &nonce, method)?;
 
 	request = request.header("time", &time);
 	request = request.header("nonce", &nonce);
@@ -318,6 +361,9 @@ pub fn create_request(url: String, method: HttpMethod, body: Option<String>) ->
 
 	if let Some(body) = body {
 		request = request.body(body.as_bytes());
+	} else if matches!(method, HttpMethod::Post) {
+		// the api rejects posts without a json body
+		request = request.body("{}".as_bytes());
 	}
 
 	Ok(request)
@@ -340,3 +386,14 @@ where
 
 	response.get_json_owned()
 }
+
+// performs the daily punch-in once per day (utc+8) when enabled
+pub fn try_punch_in() {
+	if !crate::settings::get_auto_punch_in() || crate::settings::has_punched_in_today() {
+		return;
+	}
+	// only tried once a day, since a failure ("fail" means the account has already
+	// punched in today) would otherwise be retried every time the home page is opened
+	crate::settings::set_punched_in();
+	let _ = request_json::<serde_json::Value>(Url::PunchIn);
+}
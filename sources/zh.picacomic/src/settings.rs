@@ -2,7 +2,10 @@ use aidoku::{
 	Result,
 	alloc::{String, Vec, string::ToString},
 	error,
-	imports::defaults::{DefaultValue, defaults_get, defaults_set},
+	imports::{
+		defaults::{DefaultValue, defaults_get, defaults_set},
+		std::current_date,
+	},
 };
 
 pub fn get_app_channel() -> String {
@@ -21,6 +24,66 @@ pub fn get_list_viewer() -> bool {
 	defaults_get("isListView").unwrap_or(false)
 }
 
+pub fn get_auto_punch_in() -> bool {
+	defaults_get("autoPunchIn").unwrap_or(false)
+}
+
+// days are counted in beijing time (utc+8), which is when the punch-in resets
+fn current_day() -> i64 {
+	(current_date() + 28800) / 86400
+}
+
+pub fn has_punched_in_today() -> bool {
+	defaults_get::<String>("lastPunchIn")
+		.and_then(|day| day.parse::<i64>().ok())
+		.is_some_and(|day| day == current_day())
+}
+
+pub fn set_punched_in() {
+	defaults_set(
+		"lastPunchIn",
+		DefaultValue::String(current_day().to_string()),
+	);
+}
+
+// the id and title of the comic whose details were last opened, for the favourite and like buttons
+pub fn get_last_comic() -> Option<(String, String)> {
+	let id = defaults_get::<String>("lastComic").filter(|id| !id.is_empty())?;
+	let title = defaults_get::<String>("lastComicTitle").unwrap_or_default();
+	Some((id, title))
+}
+
+pub fn set_last_comic(id: &str, title: &str, is_favourite: bool, is_liked: bool) {
+	if get_last_comic().is_none_or(|(last_id, _)| last_id != id) {
+		defaults_set("actionStatus", DefaultValue::Null);
+	}
+	defaults_set("lastComic", DefaultValue::String(id.into()));
+	defaults_set("lastComicTitle", DefaultValue::String(title.into()));
+	set_last_comic_state(is_favourite, is_liked);
+}
+
+// whether the last opened comic is favourited and liked
+pub fn get_last_comic_state() -> (bool, bool) {
+	(
+		defaults_get("lastComicFavourite").unwrap_or(false),
+		defaults_get("lastComicLiked").unwrap_or(false),
+	)
+}
+
+pub fn set_last_comic_state(is_favourite: bool, is_liked: bool) {
+	defaults_set("lastComicFavourite", DefaultValue::Bool(is_favourite));
+	defaults_set("lastComicLiked", DefaultValue::Bool(is_liked));
+}
+
+// the result of the last favourite or like action, shown in the settings
+pub fn get_action_status() -> Option<String> {
+	defaults_get::<String>("actionStatus")
+}
+
+pub fn set_action_status(status: String) {
+	defaults_set("actionStatus", DefaultValue::String(status));
+}
+
 pub fn get_username() -> Result<String> {
 	defaults_get::<String>("username").ok_or_else(|| error!("Please log in first"))
 }
@@ -154,11 +154,20 @@ impl Source for Zaimanhua {
 			.or(page_data.page_url)
 			.ok_or_else(|| error!("页面地址缺失"))?;
 
+		// the pages remember their chapter, so the page the user reached can be uploaded
+		// as the read record
 		let pages = page_urls
 			.into_iter()
-			.map(|url| Page {
-				content: PageContent::url(url),
-				..Default::default()
+			.enumerate()
+			.map(|(index, url)| {
+				let mut context = PageContext::new();
+				context.insert("comic_id".into(), comic_id.into());
+				context.insert("chapter_id".into(), chapter_id.into());
+				context.insert("page".into(), (index + 1).to_string());
+				Page {
+					content: PageContent::url_context(url, context),
+					..Default::default()
+				}
 			})
 			.collect();
 
@@ -167,7 +176,17 @@ impl Source for Zaimanhua {
 }
 
 impl ImageRequestProvider for Zaimanhua {
-	fn get_image_request(&self, url: String, _context: Option<PageContext>) -> Result<Request> {
+	fn get_image_request(&self, url: String, context: Option<PageContext>) -> Result<Request> {
+		if settings::get_token().is_some()
+			&& let Some(context) = context
+			&& let (Some(comic_id), Some(chapter_id), Some(page)) = (
+				context.get("comic_id"),
+				context.get("chapter_id"),
+				context.get("page").and_then(|p| p.parse().ok()),
+			) {
+			settings::set_last_read(comic_id, chapter_id, page);
+		}
+
 		let resolved = net::resolve_url(&url);
 		Ok(Request::get(resolved)?
 			.header("User-Agent", USER_AGENT)
@@ -247,7 +266,38 @@ impl NotificationHandler for Zaimanhua {
 				settings::clear_user_cache();
 				settings::reset_dependent_settings();
 			}
+			return;
+		}
+
+		if settings::get_token().is_none() {
+			return;
 		}
+		let status = match notification.as_str() {
+			"subscribe" | "unsubscribe" => {
+				let subscribe = notification == "subscribe";
+				let action = if subscribe { "订阅" } else { "取消订阅" };
+				match settings::get_subscribe_comic() {
+					Some(comic_id) => match net::set_subscribed(&comic_id, subscribe) {
+						Ok(()) => format!("已{action}漫画 {comic_id}"),
+						Err(err) => format!("{action}漫画 {comic_id} 失败：{err:?}"),
+					},
+					None => format!("{action}失败：漫画 ID 或链接无效"),
+				}
+			}
+			"uploadReadRecord" => match settings::get_last_read() {
+				Some((comic_id, chapter_id, page)) => {
+					match net::upload_read_record(&comic_id, &chapter_id, page) {
+						Ok(()) => format!(
+							"已上传阅读记录：漫画 {comic_id} 章节 {chapter_id} 第 {page} 页"
+						),
+						Err(err) => format!("上传阅读记录失败：{err:?}"),
+					}
+				}
+				None => "上传阅读记录失败：还没有阅读过章节".into(),
+			},
+			_ => return,
+		};
+		settings::set_sync_status(status);
 	}
 }
 
@@ -291,6 +341,26 @@ impl DynamicSettings for Zaimanhua {
 				}
 				.into(),
 			);
+
+			let mut sync_text = match settings::get_last_read() {
+				Some((comic_id, chapter_id, page)) => {
+					format!("最近阅读：漫画 {comic_id} 章节 {chapter_id} 第 {page} 页")
+				}
+				None => "最近阅读：无".into(),
+			};
+			if let Some(status) = settings::get_sync_status() {
+				sync_text = format!("{sync_text}\n{status}");
+			}
+			settings.push(
+				GroupSetting {
+					key: "syncInfo".into(),
+					title: "同步状态".into(),
+					items: Vec::new(),
+					footer: Some(sync_text.into()),
+					..Default::default()
+				}
+				.into(),
+			);
 		}
 
 		Ok(settings)
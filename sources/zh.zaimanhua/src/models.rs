@@ -309,11 +309,17 @@ pub struct SubscribeItem {
 impl From<SubscribeItem> for Manga {
 	fn from(item: SubscribeItem) -> Self {
 		let key = item.id.to_string();
+		let title = item.name.unwrap_or_default();
+		let authors = item
+			.authors
+			.filter(|a| !a.is_empty())
+			.map(|a| a.split('/').map(|s| s.trim().to_string()).collect());
 
 		Self {
 			key,
-			title: item.name.unwrap_or_default(),
+			title,
 			cover: item.cover,
+			authors,
 			..Default::default()
 		}
 	}
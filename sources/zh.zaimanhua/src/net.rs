@@ -64,6 +64,18 @@ pub mod urls {
 		format!("{V4_API_URL}/comic/sub/list?status=0&firstLetter=&page={page}&size=50")
 	}
 
+	pub fn sub_add() -> String {
+		format!("{V4_API_URL}/comic/sub/add")
+	}
+
+	pub fn sub_cancel() -> String {
+		format!("{V4_API_URL}/comic/sub/del")
+	}
+
+	pub fn record_add() -> String {
+		format!("{V4_API_URL}/comic/record/add")
+	}
+
 	pub fn manga_news() -> String {
 		let news_url = crate::NEWS_URL;
 		format!("{news_url}/manhuaqingbao")
@@ -145,6 +157,58 @@ pub fn send_authed_request<T: DeserializeOwned>(
 	Ok(resp)
 }
 
+pub fn send_authed_post<T: DeserializeOwned>(
+	url: &str,
+	token: &str,
+	body: &str,
+) -> Result<models::ApiResponse<T>> {
+	let send = |token: &str| -> Result<models::ApiResponse<T>> {
+		post_request(url)?
+			.header("Authorization", &format!("Bearer {token}"))
+			.body(body.as_bytes())
+			.json_owned()
+	};
+	let resp = send(token)?;
+
+	if resp.errno.unwrap_or(0) == 99
+		&& let Ok(Some(new_token)) = try_refresh_token()
+	{
+		return send(&new_token);
+	}
+	Ok(resp)
+}
+
+// posts a form to an endpoint that only reports success through errno
+fn send_authed_action(url: &str, body: &str) -> Result<()> {
+	let token = settings::get_token().ok_or_else(|| aidoku::error!("请先登录"))?;
+	let response: models::ApiResponse<aidoku::serde::de::IgnoredAny> =
+		send_authed_post(url, &token, body)?;
+	if response.errno.unwrap_or(-1) != 0 {
+		let errmsg = response.errmsg.as_deref().unwrap_or("未知错误");
+		aidoku::bail!("{errmsg}");
+	}
+	Ok(())
+}
+
+pub fn set_subscribed(comic_id: &str, subscribe: bool) -> Result<()> {
+	let url = if subscribe {
+		urls::sub_add()
+	} else {
+		urls::sub_cancel()
+	};
+	let body = format!("comic_id={}", encode_uri_component(comic_id));
+	send_authed_action(&url, &body)
+}
+
+pub fn upload_read_record(comic_id: &str, chapter_id: &str, page: i32) -> Result<()> {
+	let body = format!(
+		"comic_id={}&chapter_id={}&page={page}&type=1",
+		encode_uri_component(comic_id),
+		encode_uri_component(chapter_id),
+	);
+	send_authed_action(&urls::record_add(), &body)
+}
+
 pub fn login(username: &str, password: &str) -> Result<Option<String>> {
 	let password_hash = md5_hex(password);
 	let url = format!("{ACCOUNT_API}/login/passwd");
@@ -1,5 +1,5 @@
 use aidoku::{
-	alloc::{String, string::ToString},
+	alloc::{String, format, string::ToString},
 	imports::defaults::{DefaultValue, defaults_get, defaults_set, defaults_set_data},
 };
 
@@ -10,6 +10,9 @@ const JUST_LOGGED_IN_KEY: &str = "justLoggedIn";
 const USER_CACHE_KEY: &str = "userCache";
 const AUTO_CHECKIN_KEY: &str = "autoCheckin";
 const LAST_CHECKIN_KEY: &str = "lastCheckin";
+const SUBSCRIBE_COMIC_KEY: &str = "subscribeComic";
+const LAST_READ_KEY: &str = "lastRead";
+const SYNC_STATUS_KEY: &str = "syncStatus";
 const USE_PROXY_KEY: &str = "useProxy";
 const PROXY_URL_KEY: &str = "proxyUrl";
 
@@ -80,6 +83,42 @@ pub fn clear_checkin_flag() {
 	defaults_set(LAST_CHECKIN_KEY, DefaultValue::Null);
 }
 
+pub fn get_subscribe_comic() -> Option<String> {
+	defaults_get::<String>(SUBSCRIBE_COMIC_KEY)
+		.and_then(|text| crate::helpers::parse_manga_id(&text))
+}
+
+// the comic, chapter and furthest page last read in aidoku
+pub fn get_last_read() -> Option<(String, String, i32)> {
+	let last_read = defaults_get::<String>(LAST_READ_KEY)?;
+	let mut parts = last_read.splitn(3, '/');
+	let comic_id = parts.next()?.to_string();
+	let chapter_id = parts.next()?.to_string();
+	let page = parts.next().and_then(|p| p.parse().ok()).unwrap_or(1);
+	Some((comic_id, chapter_id, page))
+}
+
+pub fn set_last_read(comic_id: &str, chapter_id: &str, page: i32) {
+	if get_last_read().is_some_and(|(last_comic, last_chapter, last_page)| {
+		last_comic == comic_id && last_chapter == chapter_id && last_page >= page
+	}) {
+		return;
+	}
+	defaults_set(
+		LAST_READ_KEY,
+		DefaultValue::String(format!("{comic_id}/{chapter_id}/{page}")),
+	);
+}
+
+// the result of the last subscribe or read record action, shown in the settings
+pub fn get_sync_status() -> Option<String> {
+	defaults_get::<String>(SYNC_STATUS_KEY)
+}
+
+pub fn set_sync_status(status: String) {
+	defaults_set(SYNC_STATUS_KEY, DefaultValue::String(status));
+}
+
 pub fn get_use_proxy() -> bool {
 	defaults_get::<bool>(USE_PROXY_KEY).unwrap_or(false)
 }
@@ -126,4 +165,6 @@ pub fn is_cache_stale() -> bool {
 
 pub fn reset_dependent_settings() {
 	defaults_set(AUTO_CHECKIN_KEY, DefaultValue::Null);
+	defaults_set(LAST_READ_KEY, DefaultValue::Null);
+	defaults_set(SYNC_STATUS_KEY, DefaultValue::Null);
 }
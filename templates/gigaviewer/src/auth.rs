@@ -10,7 +10,7 @@ use aidoku::{
 };
 
 static EMAIL_KEY: &str = "login.username";
-// static PASSWORD_KEY: &str = "login.password";
+static PASSWORD_KEY: &str = "login.password";
 static COOKIE_KEY: &str = "login.cookie";
 
 pub fn login(base_url: &str, email: &str, password: &str) -> Result<bool> {
@@ -39,6 +39,26 @@ pub fn login(base_url: &str, email: &str, password: &str) -> Result<bool> {
 	}
 }
 
+// checks if the saved session cookie is still accepted, since the account page
+// shows the login form once the session has expired
+pub fn is_session_valid(base_url: &str) -> Result<bool> {
+	let html = Request::get(format!("{base_url}/user_account"))?
+		.authed()
+		.html()?;
+	Ok(html.select_first("input[name=email_address]").is_none())
+}
+
+// logs in again with the saved credentials, for when the session cookie has expired
+pub fn relogin(base_url: &str) -> Result<bool> {
+	let (Some(email), Some(password)) = (
+		defaults_get::<String>(EMAIL_KEY),
+		defaults_get::<String>(PASSWORD_KEY),
+	) else {
+		return Ok(false);
+	};
+	login(base_url, &email, &password)
+}
+
 pub fn logout() {
 	defaults_set(COOKIE_KEY, DefaultValue::Null);
 }
@@ -135,26 +135,38 @@ pub trait Impl {
 		Ok(new_manga)
 	}
 
-	fn get_page_list(
-		&self,
-		_params: &Params,
-		_manga: Manga,
-		chapter: Chapter,
-	) -> Result<Vec<Page>> {
+	fn get_page_list(&self, params: &Params, _manga: Manga, chapter: Chapter) -> Result<Vec<Page>> {
 		let Some(url) = chapter.url else {
 			return Err(AidokuError::message("URLがありません"));
 		};
-		let html = Request::get(url)?.authed().html()?;
+		let get_episode = || -> Result<GigaEpisode> {
+			let html = Request::get(&url)?.authed().html()?;
+			html.select_first("script#episode-json")
+				.and_then(|e| e.attr("data-value"))
+				.ok_or(AidokuError::message("このチャプターは非公開です"))
+				.and_then(|v| Ok(serde_json::from_str::<GigaEpisode>(v.as_ref())?))
+		};
+
+		let mut episode = get_episode()?;
 
-		let episode = html
-			.select_first("script#episode-json")
-			.and_then(|e| e.attr("data-value"))
-			.ok_or(AidokuError::message("このチャプターは非公開です"))
-			.and_then(|v| Ok(serde_json::from_str::<GigaEpisode>(v.as_ref())?))?;
+		// rented episodes are only readable with a valid session, so log in again if it expired
+		if episode.readable_product.page_structure.is_none()
+			&& auth::is_logged_in()
+			&& !auth::is_session_valid(&params.base_url)?
+			&& auth::relogin(&params.base_url)?
+		{
+			episode = get_episode()?;
+		}
+
+		let Some(page_structure) = episode.readable_product.page_structure else {
+			return Err(AidokuError::message(if auth::is_logged_in() {
+				"このチャプターはレンタルまたは購入が必要です"
+			} else {
+				"このチャプターを読むにはログインが必要です"
+			}));
+		};
 
-		Ok(episode
-			.readable_product
-			.page_structure
+		Ok(page_structure
 			.pages
 			.iter()
 			.filter_map(|page| {
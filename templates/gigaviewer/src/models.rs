@@ -14,7 +14,8 @@ pub struct GigaEpisode {
 #[derive(Default, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase", default)]
 pub struct GigaReadableProduct {
-	pub page_structure: GigaPageStructure,
+	// missing when the episode needs to be rented or purchased first
+	pub page_structure: Option<GigaPageStructure>,
 }
 
 #[derive(Default, Deserialize, Debug, Clone)]
@@ -50,6 +51,19 @@ pub struct GigaPaginationReadableProduct {
 #[derive(Default, Deserialize, Debug, Clone)]
 pub struct GigaPaginationReadableProductStatus {
 	pub label: Option<String>, // is_free, is_rentable, is_purchasable, unpublished, has_rented
+	pub rental_end_at: Option<String>,
+}
+
+impl GigaPaginationReadableProduct {
+	pub fn label(&self) -> Option<&str> {
+		self.status.as_ref()?.label.as_deref()
+	}
+}
+
+// converts an api timestamp into a short yyyy/MM/dd date for chapter titles
+fn format_date(date: &str) -> Option<String> {
+	let date = date.get(..10)?;
+	Some(date.replace('-', "/"))
 }
 
 impl From<GigaPaginationReadableProduct> for Chapter {
@@ -58,20 +72,43 @@ impl From<GigaPaginationReadableProduct> for Chapter {
 			.title
 			.clone()
 			.and_then(crate::parser::parse_chapter_number);
+		let label = val.label().unwrap_or_default();
+		let locked = !label.is_empty() && label != "is_free" && label != "has_rented";
+		let status = match label {
+			"is_rentable" => Some("レンタル".into()),
+			"is_purchasable" => Some("購入".into()),
+			"has_rented" => Some(
+				val.status
+					.as_ref()
+					.and_then(|status| status.rental_end_at.as_deref())
+					.and_then(format_date)
+					.map(|date| format!("{date}までレンタル中"))
+					.unwrap_or_else(|| "レンタル中".into()),
+			),
+			"unpublished" => Some(
+				val.display_open_at
+					.as_deref()
+					.and_then(format_date)
+					.map(|date| format!("{date}公開"))
+					.unwrap_or_else(|| "非公開".into()),
+			),
+			_ => None,
+		};
+		let title = match (val.title, status) {
+			(Some(title), Some(status)) => Some(format!("{title}（{status}）")),
+			(None, Some(status)) => Some(status),
+			(title, None) => title,
+		};
 		Chapter {
 			key: format!("/episode/{}", val.readable_product_id.unwrap_or_default()),
-			title: val.title,
+			title,
 			chapter_number,
 			date_uploaded: val
 				.display_open_at
 				.and_then(|str| parse_date(str, "yyyy-MM-dd'T'HH:mm:ss'Z'")),
 			url: val.viewer_uri,
 			thumbnail: val.thumbnail_uri,
-			locked: val
-				.status
-				.and_then(|status| status.label)
-				.map(|label| label != "is_free" && label != "has_rented")
-				.unwrap_or_default(),
+			locked,
 			..Default::default()
 		}
 	}
@@ -124,6 +124,7 @@ pub fn parse_chapters_paginated(
 				.unwrap_or_default()
 		});
 
+	let hide_paid_only = defaults_get::<bool>("hidePaidOnly").unwrap_or(false);
 	let mut chapters = Vec::new();
 	let mut offset = 0;
 
@@ -148,7 +149,11 @@ pub fn parse_chapters_paginated(
 		}
 
 		offset += json.len();
-		chapters.extend(json.into_iter().map(|product| product.into()));
+		chapters.extend(
+			json.into_iter()
+				.filter(|product| !hide_paid_only || product.label() != Some("is_purchasable"))
+				.map(|product| product.into()),
+		);
 	}
 
 	Ok(chapters)
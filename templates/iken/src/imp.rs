@@ -1,11 +1,12 @@
-use crate::{helpers, models::*, Params};
+use crate::{Params, helpers, models::*, settings};
 use aidoku::{
-	alloc::{string::ToString, vec, String, Vec},
+	AidokuError, Chapter, DeepLinkResult, Filter, FilterValue, HomeComponent, HomeComponentValue,
+	HomeLayout, Listing, Manga, MangaPageResult, MultiSelectFilter, Page, PageContent, PageContext,
+	Result,
+	alloc::{String, Vec, string::ToString, vec},
 	helpers::{element::ElementHelpers, string::StripPrefixOrSelf, uri::QueryParameters},
 	imports::{html::Html, net::Request, std::send_partial_result},
 	prelude::*,
-	Chapter, DeepLinkResult, FilterValue, HomeComponent, HomeComponentValue, HomeLayout, Manga,
-	MangaPageResult, Page, PageContent, PageContext, Result,
 };
 
 const PER_PAGE: i32 = 18;
@@ -67,6 +68,30 @@ pub trait Impl {
 		})
 	}
 
+	fn get_manga_list(
+		&self,
+		params: &Params,
+		listing: Listing,
+		page: i32,
+	) -> Result<MangaPageResult> {
+		let sort_value = match listing.id.as_str() {
+			"new" => "createdAt",
+			"latest" => "updatedAt",
+			"popular" => "totalViews",
+			_ => return Err(AidokuError::message("Invalid listing")),
+		};
+		// passed as a select value, since sources order their sort filter options differently
+		self.get_search_manga_list(
+			params,
+			None,
+			page,
+			vec![FilterValue::Select {
+				id: "orderBy".into(),
+				value: sort_value.into(),
+			}],
+		)
+	}
+
 	fn get_manga_update(
 		&self,
 		params: &Params,
@@ -97,7 +122,7 @@ pub trait Impl {
 		}
 
 		if needs_chapters {
-			manga.chapters = Some(if params.fetch_full_chapter_list {
+			let mut chapters = if params.fetch_full_chapter_list {
 				let mut response =
 					Request::get(format!("{api_url}/api/chapters?postId={}", data.post.id))?
 						.header("Referer", &format!("{}/", params.base_url))
@@ -106,7 +131,11 @@ pub trait Impl {
 				new_data.post.chapters(&params.base_url, data.post.slug)
 			} else {
 				data.post.chapters(&params.base_url)
-			});
+			};
+			if settings::hide_locked_chapters() {
+				chapters.retain(|chapter| !chapter.locked);
+			}
+			manga.chapters = Some(chapters);
 		}
 
 		Ok(manga)
@@ -382,6 +411,28 @@ pub trait Impl {
 		Ok(HomeLayout { components })
 	}
 
+	fn get_dynamic_filters(&self, params: &Params) -> Result<Vec<Filter>> {
+		let (options, ids) = Request::get(format!("{}/api/genres", params.get_api_url()))?
+			.header("Referer", &format!("{}/", params.base_url))
+			.json_owned::<Vec<IkenGenre>>()?
+			.into_iter()
+			.map(|genre| (genre.name.into(), genre.id.to_string().into()))
+			.unzip();
+
+		Ok(vec![
+			MultiSelectFilter {
+				id: "genreIds".into(),
+				title: Some(params.genre_filter_title.clone()),
+				is_genre: true,
+				can_exclude: false,
+				options,
+				ids: Some(ids),
+				..Default::default()
+			}
+			.into(),
+		])
+	}
+
 	fn get_image_request(
 		&self,
 		params: &Params,
@@ -1,14 +1,16 @@
 #![no_std]
 use aidoku::{
-	alloc::{borrow::Cow, String, Vec},
+	Chapter, DeepLinkHandler, DeepLinkResult, DynamicFilters, Filter, FilterValue, Home,
+	HomeLayout, ImageRequestProvider, Listing, ListingProvider, Manga, MangaPageResult, Page,
+	PageContext, Result, Source,
+	alloc::{String, Vec, borrow::Cow},
 	imports::net::Request,
-	Chapter, DeepLinkHandler, DeepLinkResult, FilterValue, Home, HomeLayout, ImageRequestProvider,
-	Manga, MangaPageResult, Page, PageContext, Result, Source,
 };
 
 mod helpers;
 mod imp;
 pub mod models;
+mod settings;
 
 pub use imp::Impl;
 
@@ -20,6 +22,8 @@ pub struct Params {
 	// the post endpoint doesn't contain all keys for the chapter objects
 	pub fetch_full_chapter_list: bool,
 	pub get_sort_value: fn(i32) -> Cow<'static, str>,
+	// the title of the genre filter fetched from the api
+	pub genre_filter_title: Cow<'static, str>,
 }
 
 impl Default for Params {
@@ -29,7 +33,17 @@ impl Default for Params {
 			api_url: None,
 			use_slug_series_keys: false,
 			fetch_full_chapter_list: false,
-			get_sort_value: |_| "".into(),
+			get_sort_value: |index| {
+				match index {
+					0 => "createdAt",
+					1 => "updatedAt",
+					2 => "totalViews",
+					3 => "postTitle",
+					_ => "createdAt",
+				}
+				.into()
+			},
+			genre_filter_title: "Genres".into(),
 		}
 	}
 }
@@ -80,6 +94,12 @@ impl<T: Impl> Source for Iken<T> {
 	}
 }
 
+impl<T: Impl> ListingProvider for Iken<T> {
+	fn get_manga_list(&self, listing: Listing, page: i32) -> Result<MangaPageResult> {
+		self.inner.get_manga_list(&self.params, listing, page)
+	}
+}
+
 impl<T: Impl> Home for Iken<T> {
 	fn get_home(&self) -> Result<HomeLayout> {
 		self.inner.get_home(&self.params)
@@ -92,6 +112,12 @@ impl<T: Impl> ImageRequestProvider for Iken<T> {
 	}
 }
 
+impl<T: Impl> DynamicFilters for Iken<T> {
+	fn get_dynamic_filters(&self) -> Result<Vec<Filter>> {
+		self.inner.get_dynamic_filters(&self.params)
+	}
+}
+
 impl<T: Impl> DeepLinkHandler for Iken<T> {
 	fn handle_deep_link(&self, url: String) -> Result<Option<DeepLinkResult>> {
 		self.inner.handle_deep_link(&self.params, url)
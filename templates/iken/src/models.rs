@@ -1,10 +1,10 @@
 use crate::Params;
 use aidoku::{
-	alloc::{string::ToString, vec, String, Vec},
+	Chapter, Manga, MangaStatus, Viewer,
+	alloc::{String, Vec, string::ToString, vec},
 	helpers::element::ElementHelpers,
 	imports::html::Html,
 	prelude::*,
-	Chapter, Manga, MangaStatus, Viewer,
 };
 use serde::Deserialize;
 
@@ -72,6 +72,12 @@ pub struct Genre<'a> {
 	name: &'a str,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+pub struct IkenGenre {
+	pub id: i32,
+	pub name: String,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct Author<'a> {
 	name: &'a str,
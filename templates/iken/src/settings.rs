@@ -0,0 +1,7 @@
+use aidoku::imports::defaults::defaults_get;
+
+const HIDE_LOCKED_CHAPTERS_KEY: &str = "hideLockedChapters";
+
+pub fn hide_locked_chapters() -> bool {
+	defaults_get::<bool>(HIDE_LOCKED_CHAPTERS_KEY).unwrap_or(false)
+}
@@ -1,7 +1,7 @@
 use crate::{LoadMoreStrategy, Params};
 use aidoku::{
 	FilterValue, Result,
-	alloc::{String, string::ToString, vec::Vec},
+	alloc::{String, string::ToString, vec, vec::Vec},
 	helpers::uri::QueryParameters,
 	imports::{
 		html::{Document, Element},
@@ -122,16 +122,11 @@ pub fn get_search_request(
 		match filter {
 			FilterValue::Text { id, value } => qs.push(&id, Some(&value)),
 			FilterValue::Sort { id, index, .. } => {
-				let value = match index {
-					0 => "",
-					1 => "latest",
-					2 => "alphabet",
-					3 => "rating",
-					4 => "trending",
-					5 => "views",
-					6 => "new-manga",
-					_ => "",
-				};
+				let value = usize::try_from(index)
+					.ok()
+					.and_then(|index| params.orderby_keys.get(index))
+					.copied()
+					.unwrap_or_default();
 				qs.push(&id, Some(value));
 			}
 			FilterValue::Select { id, value } => {
@@ -202,38 +197,36 @@ pub fn get_search_load_more_request(
 				index, ascending, ..
 			} => {
 				let asc = if ascending { "ASC" } else { "DESC" };
-				match index {
-					0 => continue,
-					1 => {
-						// latest
+				let key = usize::try_from(index)
+					.ok()
+					.and_then(|index| params.orderby_keys.get(index))
+					.copied()
+					.unwrap_or_default();
+				match key {
+					"latest" => {
 						qs.push("vars[orderby]", Some("meta_value_num"));
 						qs.push("vars[meta_key]", Some("_latest_update"));
 						qs.push("vars[order]", Some(asc));
 					}
-					2 => {
-						// alphabet
+					"alphabet" => {
 						qs.push("vars[orderby]", Some("post_title"));
 						qs.push("vars[order]", Some(if ascending { "DESC" } else { "ASC" }));
 					}
-					3 => {
-						// rating
+					"rating" => {
 						qs.push("vars[orderby][query_average_reviews]", Some(asc));
 						qs.push("vars[orderby][query_total_reviews]", Some(asc));
 					}
-					4 => {
-						// trending
+					"trending" => {
 						qs.push("vars[orderby]", Some("meta_value_num"));
 						qs.push("vars[meta_key]", Some("_wp_manga_week_views_value"));
 						qs.push("vars[order]", Some(asc));
 					}
-					5 => {
-						// views
+					"views" => {
 						qs.push("vars[orderby]", Some("meta_value_num"));
 						qs.push("vars[meta_key]", Some("_wp_manga_views"));
 						qs.push("vars[order]", Some(asc));
 					}
-					6 => {
-						// new
+					"new-manga" => {
 						qs.push("vars[orderby]", Some("date"));
 						qs.push("vars[order]", Some(asc));
 					}
@@ -305,6 +298,24 @@ pub fn get_search_load_more_request(
 		.header("Content-Type", "application/x-www-form-urlencoded"))
 }
 
+// gets the sort filter for the default popular, latest, new, trending and rating listings
+pub fn get_listing_filters(params: &Params, id: &str) -> Option<Vec<FilterValue>> {
+	let key = match id {
+		"popular" => "views",
+		"latest" => "latest",
+		"new" => "new-manga",
+		"trending" => "trending",
+		"rating" => "rating",
+		_ => return None,
+	};
+	let index = params.orderby_keys.iter().position(|k| *k == key)?;
+	Some(vec![FilterValue::Sort {
+		id: "m_orderby".into(),
+		index: index as i32,
+		ascending: false,
+	}])
+}
+
 // parses chapter date string (either relative or with the configured format)
 pub fn parse_chapter_date(params: &Params, date: &str) -> i64 {
 	let result = parse_date_with_options(
@@ -370,11 +370,13 @@ pub trait Impl {
 
 	fn get_manga_list(
 		&self,
-		_params: &Params,
-		_listing: aidoku::Listing,
-		_page: i32,
+		params: &Params,
+		listing: aidoku::Listing,
+		page: i32,
 	) -> Result<MangaPageResult> {
-		todo!()
+		let filters = (params.listing_filters)(params, &listing.id)
+			.ok_or_else(|| error!("Invalid listing: {}", listing.id))?;
+		self.get_search_manga_list(params, None, page, filters)
 	}
 
 	fn get_home(&self, params: &Params) -> Result<HomeLayout> {
@@ -45,6 +45,11 @@ pub struct Params {
 	pub genre_endpoint: Cow<'static, str>,
 	// get the path for the search endpoint with a given page number
 	pub search_page: fn(i32) -> Cow<'static, str>,
+	// the m_orderby values for each index of the sort filter
+	pub orderby_keys: Cow<'static, [&'static str]>,
+	// get the filters used to fetch a listing with a given id, or none if the listing isn't supported
+	// override to change the default listings or add extra ones
+	pub listing_filters: fn(&Params, &str) -> Option<Vec<FilterValue>>,
 	pub search_manga_selector: Cow<'static, str>,
 	pub search_manga_url_selector: Cow<'static, str>,
 	pub search_manga_title_selector: Cow<'static, str>,
@@ -89,6 +94,16 @@ impl Default for Params {
 					format!("page/{page}/").into()
 				}
 			},
+			orderby_keys: Cow::Borrowed(&[
+				"",
+				"latest",
+				"alphabet",
+				"rating",
+				"trending",
+				"views",
+				"new-manga",
+			]),
+			listing_filters: helpers::get_listing_filters,
 			search_manga_selector: "div.c-tabs-item__content , .manga__item".into(),
 			search_manga_url_selector: "div.post-title a".into(),
 			search_manga_title_selector: "div.post-title a".into(),
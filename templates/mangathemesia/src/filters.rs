@@ -0,0 +1,174 @@
+use aidoku::{
+	CheckFilter, Filter, MultiSelectFilter, SelectFilter,
+	alloc::{String, Vec, borrow::Cow},
+	imports::html::{Document, Element},
+};
+use serde::Deserialize;
+
+// a filter from a source's fallback_filters.json
+#[derive(Deserialize)]
+#[serde(tag = "type", rename_all = "kebab-case")]
+enum FallbackFilter {
+	MultiSelect {
+		id: String,
+		title: Option<String>,
+		options: Vec<String>,
+		ids: Option<Vec<String>>,
+	},
+	Select {
+		id: String,
+		title: Option<String>,
+		options: Vec<String>,
+		ids: Option<Vec<String>>,
+	},
+}
+
+// parses the genre, status, type and order filters (and any others) from the advanced
+// search form on the manga directory page
+pub fn parse_filters(html: &Document) -> Vec<Filter> {
+	let mut filters: Vec<Filter> = Vec::new();
+	let mut has_novels = false;
+
+	let Some(form_filters) = html.select("form.filters div.filter, .quickfilter div.filter") else {
+		return filters;
+	};
+
+	for element in form_filters {
+		let Some(input) = element.select_first("li input") else {
+			continue;
+		};
+		let Some(name) = input.attr("name") else {
+			continue;
+		};
+		let is_checkbox = input.attr("type").is_some_and(|t| t == "checkbox");
+
+		let (options, ids): (Vec<Cow<'static, str>>, Vec<Cow<'static, str>>) = element
+			.select("li")
+			.map(|els| {
+				els.filter_map(|el| {
+					let value = el.select_first("input")?.attr("value").unwrap_or_default();
+					let label = el.select_first("label")?.text()?;
+					Some((label.into(), value.into()))
+				})
+				.unzip()
+			})
+			.unwrap_or_default();
+		if options.is_empty() {
+			continue;
+		}
+
+		if name == "type" && has_novel_option(&ids) {
+			has_novels = true;
+		}
+
+		let title = filter_title(&element, &name);
+		filters.push(if is_checkbox {
+			multi_select_filter(name, Some(title), options, Some(ids))
+		} else {
+			SelectFilter {
+				id: name.into(),
+				title: Some(title.into()),
+				options,
+				ids: Some(ids),
+				..Default::default()
+			}
+			.into()
+		});
+	}
+
+	if has_novels {
+		filters.push(novel_filter());
+	}
+	filters
+}
+
+// parses the filters bundled with a source, used when the search form can't be scraped
+pub fn parse_fallback_filters(json: &str) -> Vec<Filter> {
+	let fallback: Vec<FallbackFilter> = serde_json::from_str(json).unwrap_or_default();
+	let mut has_novels = false;
+	let mut filters: Vec<Filter> = fallback
+		.into_iter()
+		.map(|filter| match filter {
+			FallbackFilter::MultiSelect {
+				id,
+				title,
+				options,
+				ids,
+			} => multi_select_filter(
+				id,
+				title,
+				options.into_iter().map(Cow::from).collect(),
+				ids.map(|ids| ids.into_iter().map(Cow::from).collect()),
+			),
+			FallbackFilter::Select {
+				id,
+				title,
+				options,
+				ids,
+			} => {
+				if id == "type" && has_novel_option(ids.as_ref().unwrap_or(&options)) {
+					has_novels = true;
+				}
+				SelectFilter {
+					id: id.into(),
+					title: title.map(Cow::from),
+					options: options.into_iter().map(Cow::from).collect(),
+					ids: ids.map(|ids| ids.into_iter().map(Cow::from).collect()),
+					..Default::default()
+				}
+				.into()
+			}
+		})
+		.collect();
+
+	if has_novels {
+		filters.push(novel_filter());
+	}
+	filters
+}
+
+// genres can be excluded by prefixing their id with `-`
+fn multi_select_filter(
+	id: String,
+	title: Option<String>,
+	options: Vec<Cow<'static, str>>,
+	ids: Option<Vec<Cow<'static, str>>>,
+) -> Filter {
+	let is_genre = id.starts_with("genre");
+	MultiSelectFilter {
+		id: id.into(),
+		title: title.map(Cow::from),
+		is_genre,
+		can_exclude: is_genre,
+		options,
+		ids,
+		..Default::default()
+	}
+	.into()
+}
+
+fn has_novel_option<T: AsRef<str>>(ids: &[T]) -> bool {
+	ids.iter()
+		.any(|id| id.as_ref().eq_ignore_ascii_case("novel"))
+}
+
+// a check to hide novels, for sources whose type filter has a novel option
+fn novel_filter() -> Filter {
+	CheckFilter {
+		id: "novel".into(),
+		title: Some("Novels".into()),
+		name: Some("Hide novels".into()),
+		..Default::default()
+	}
+	.into()
+}
+
+// the dropdown button text, excluding the selected value count
+fn filter_title(element: &Element, name: &str) -> String {
+	element
+		.select_first("button")
+		.and_then(|el| el.own_text())
+		.map(|s| s.trim().into())
+		.filter(|s: &String| !s.is_empty())
+		.unwrap_or_else(|| name.trim_end_matches("[]").into())
+}
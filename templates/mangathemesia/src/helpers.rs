@@ -1,3 +1,4 @@
+use crate::models::{TsReader, TsReaderSource};
 use aidoku::{
 	alloc::{format, string::String, vec::Vec},
 	imports::html::Element,
@@ -23,6 +24,32 @@ pub fn extract_images(content: &str) -> Vec<String> {
 	serde_json::from_str::<Vec<String>>(&slice).unwrap_or_default()
 }
 
+// extracts the image servers from the ts_reader.run payload, with the preferred server first
+pub fn extract_reader_sources(content: &str, preferred: Option<usize>) -> Vec<TsReaderSource> {
+	let Some(reader) = extract_between(content, "ts_reader.run(", ");")
+		.and_then(|json| serde_json::from_str::<TsReader>(json).ok())
+	else {
+		return Vec::new();
+	};
+	let mut sources: Vec<TsReaderSource> = reader
+		.sources
+		.into_iter()
+		.filter(|source| !source.images.is_empty())
+		.collect();
+	let preferred = preferred
+		.filter(|&idx| idx < sources.len())
+		.or_else(|| {
+			let default_source = reader.default_source?;
+			sources.iter().position(|s| s.source == default_source)
+		})
+		.unwrap_or(0);
+	if preferred < sources.len() {
+		let source = sources.remove(preferred);
+		sources.insert(0, source);
+	}
+	sources
+}
+
 pub fn extract_between<'a>(s: &'a str, start: &str, end: &str) -> Option<&'a str> {
 	s.find(start).and_then(|start_idx| {
 		let after_start = &s[start_idx + start.len()..];
@@ -1,14 +1,16 @@
 use crate::{
-	Params,
+	Params, filters,
 	helpers::{self, ElementImageAttr},
+	settings,
 };
 use aidoku::{
-	Chapter, ContentRating, DeepLinkResult, FilterValue, HomeComponent, HomeComponentValue,
-	HomeLayout, Link, Manga, MangaPageResult, MangaStatus, MangaWithChapter, Page, PageContent,
-	PageContext, Result, Viewer,
+	Chapter, ContentRating, DeepLinkResult, Filter, FilterValue, HomeComponent, HomeComponentValue,
+	HomeLayout, ImageResponse, Link, Manga, MangaPageResult, MangaStatus, MangaWithChapter, Page,
+	PageContent, PageContext, Result, Viewer,
 	alloc::{String, Vec, string::ToString, vec},
 	helpers::{string::StripPrefixOrSelf, uri::QueryParameters},
 	imports::{
+		canvas::ImageRef,
 		html::{Document, Html},
 		net::Request,
 		std::{current_date, parse_date_with_options, send_partial_result},
@@ -34,22 +36,17 @@ pub trait Impl {
 			qs.push("title", query.as_deref());
 		}
 
+		let mut hide_novels = false;
+
 		for filter in filters {
 			match filter {
 				FilterValue::Text { id, value } => {
 					qs.push(&id, Some(&value));
 				}
-				FilterValue::Sort { id, index, .. } => {
-					let value = match index {
-						0 => "",
-						1 => "title",
-						2 => "titlereverse",
-						3 => "update",
-						4 => "latest",
-						5 => "popular",
-						_ => "",
-					};
-					qs.push(&id, Some(value));
+				FilterValue::Check { id, value } => {
+					if id == "novel" {
+						hide_novels = value == 1;
+					}
 				}
 				FilterValue::Select { id, value } => {
 					qs.set(&id, Some(&value));
@@ -78,6 +75,9 @@ pub trait Impl {
 				.select(".utao .uta .imgu, .listupd .bs .bsx, .listo .bs .bsx")
 				.map(|els| {
 					els.filter_map(|el| {
+						if hide_novels && el.select_first("span.type.Novel, .novelabel").is_some() {
+							return None;
+						}
 						let link = el.select_first("a")?;
 						Some(Manga {
 							key: link
@@ -280,6 +280,36 @@ pub trait Impl {
 	fn get_page_list(&self, params: &Params, _manga: Manga, chapter: Chapter) -> Result<Vec<Page>> {
 		let url = format!("{}{}", params.base_url, chapter.key);
 		let response = Request::get(&url)?.string()?;
+
+		// prefer the ts_reader payload, since it lists every image server
+		let sources = helpers::extract_reader_sources(&response, settings::get_preferred_server());
+		if let Some((primary, alternates)) = sources.split_first() {
+			return Ok(primary
+				.images
+				.iter()
+				.enumerate()
+				.map(|(idx, url)| {
+					// the same page on the other servers, tried in order if this one fails
+					let fallback = alternates
+						.iter()
+						.filter_map(|source| source.images.get(idx))
+						.map(|s| s.as_str())
+						.collect::<Vec<_>>()
+						.join("\n");
+					Page {
+						content: if fallback.is_empty() {
+							PageContent::url(url)
+						} else {
+							let mut context = PageContext::new();
+							context.insert("fallback".into(), fallback);
+							PageContent::url_context(url, context)
+						},
+						..Default::default()
+					}
+				})
+				.collect());
+		}
+
 		let html = Html::parse_fragment_with_url(&response, &url)?;
 
 		let pages: Vec<Page> = html
@@ -323,6 +353,34 @@ pub trait Impl {
 			.header("Referer", &format!("{}/", params.base_url)))
 	}
 
+	fn process_page_image(
+		&self,
+		params: &Params,
+		response: ImageResponse,
+		context: Option<PageContext>,
+	) -> Result<ImageRef> {
+		let fallback = context.as_ref().and_then(|c| c.get("fallback"));
+		let Some(fallback) = fallback.filter(|_| !(200..300).contains(&response.code)) else {
+			return Ok(response.image);
+		};
+
+		// the image server failed, so try the same page on the other servers in order
+		for url in fallback.split('\n') {
+			let Ok(mut response) = self
+				.get_image_request(params, url.into(), None)
+				.and_then(|req| Ok(req.send()?))
+			else {
+				continue;
+			};
+			if (200..300).contains(&response.status_code())
+				&& let Ok(data) = response.get_data()
+			{
+				return Ok(ImageRef::new(&data));
+			}
+		}
+		bail!("Failed to load image")
+	}
+
 	fn get_home(&self, params: &Params) -> Result<HomeLayout> {
 		let html = Request::get(&params.base_url)?.html()?;
 
@@ -559,6 +617,20 @@ pub trait Impl {
 		Ok(HomeLayout { components })
 	}
 
+	fn get_dynamic_filters(&self, params: &Params) -> Result<Vec<Filter>> {
+		if params.use_dynamic_filters {
+			let url = format!("{}{}/", params.base_url, params.manga_url_directory);
+			let filters = Request::get(url)
+				.and_then(|req| Ok(req.html()?))
+				.map(|html| filters::parse_filters(&html))
+				.unwrap_or_default();
+			if !filters.is_empty() {
+				return Ok(filters);
+			}
+		}
+		Ok(filters::parse_fallback_filters(&params.fallback_filters))
+	}
+
 	fn handle_deep_link(&self, params: &Params, url: String) -> Result<Option<DeepLinkResult>> {
 		let Some(path) = url.strip_prefix(params.base_url.as_ref()) else {
 			return Ok(None);
@@ -1,13 +1,17 @@
 #![no_std]
 use aidoku::{
-	Chapter, DeepLinkHandler, DeepLinkResult, FilterValue, Home, HomeLayout, ImageRequestProvider,
-	Manga, MangaPageResult, Page, PageContext, Result, Source,
+	Chapter, DeepLinkHandler, DeepLinkResult, DynamicFilters, Filter, FilterValue, Home,
+	HomeLayout, ImageRequestProvider, ImageResponse, Manga, MangaPageResult, Page, PageContext,
+	PageImageProcessor, Result, Source,
 	alloc::{String, Vec, borrow::Cow},
-	imports::net::Request,
+	imports::{canvas::ImageRef, net::Request},
 };
 
+mod filters;
 pub mod helpers;
 mod imp;
+mod models;
+mod settings;
 
 pub use imp::Impl;
 
@@ -17,6 +21,10 @@ pub struct Params {
 	pub date_format: Cow<'static, str>,
 	pub date_locale: Cow<'static, str>,
 	pub mark_all_nsfw: bool,
+	// if filters should be scraped from the advanced search form
+	pub use_dynamic_filters: bool,
+	// filters in the filters.json format, used when the search form can't be scraped
+	pub fallback_filters: Cow<'static, str>,
 	pub series_title_selector: Cow<'static, str>,
 	pub series_cover_selector: Cow<'static, str>,
 	pub series_artist_selector: Cow<'static, str>,
@@ -36,6 +44,8 @@ impl Default for Params {
 			date_format: "MMMM dd, yyyy".into(),
 			date_locale: "en_US_POSIX".into(),
 			mark_all_nsfw: false,
+			use_dynamic_filters: true,
+			fallback_filters: "[]".into(),
 			series_title_selector: "h1.entry-title, .ts-breadcrumb li:last-child span".into(),
 			series_cover_selector: ".infomanga > div[itemprop=image] img, .thumb img".into(),
 			series_artist_selector: helpers::selector(
@@ -133,12 +143,29 @@ impl<T: Impl> ImageRequestProvider for MangaThemesia<T> {
 	}
 }
 
+impl<T: Impl> PageImageProcessor for MangaThemesia<T> {
+	fn process_page_image(
+		&self,
+		response: ImageResponse,
+		context: Option<PageContext>,
+	) -> Result<ImageRef> {
+		self.inner
+			.process_page_image(&self.params, response, context)
+	}
+}
+
 impl<T: Impl> Home for MangaThemesia<T> {
 	fn get_home(&self) -> Result<HomeLayout> {
 		self.inner.get_home(&self.params)
 	}
 }
 
+impl<T: Impl> DynamicFilters for MangaThemesia<T> {
+	fn get_dynamic_filters(&self) -> Result<Vec<Filter>> {
+		self.inner.get_dynamic_filters(&self.params)
+	}
+}
+
 impl<T: Impl> DeepLinkHandler for MangaThemesia<T> {
 	fn handle_deep_link(&self, url: String) -> Result<Option<DeepLinkResult>> {
 		self.inner.handle_deep_link(&self.params, url)
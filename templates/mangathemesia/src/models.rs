@@ -0,0 +1,18 @@
+use aidoku::alloc::{String, Vec};
+use serde::Deserialize;
+
+// the payload passed to ts_reader.run on chapter pages
+#[derive(Deserialize)]
+pub struct TsReader {
+	#[serde(default)]
+	pub sources: Vec<TsReaderSource>,
+	#[serde(rename = "defaultSource")]
+	pub default_source: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct TsReaderSource {
+	pub source: String,
+	#[serde(default)]
+	pub images: Vec<String>,
+}
@@ -0,0 +1,8 @@
+use aidoku::{alloc::String, imports::defaults::defaults_get};
+
+const PREFERRED_SERVER_KEY: &str = "preferredServer";
+
+// the index of the preferred ts_reader image server, if one was selected
+pub fn get_preferred_server() -> Option<usize> {
+	defaults_get::<String>(PREFERRED_SERVER_KEY).and_then(|s| s.parse().ok())
+}